@@ -0,0 +1,3257 @@
+// Copyright 2015-2018 Benjamin Fry <benjaminfry@me.com>
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! The derive macro of [`enum-try-as-inner`](https://docs.rs/enum-try-as-inner).
+//!
+//! This crate should not be used directly, the macro is re-exported by `enum-try-as-inner`
+//! alongside the traits the generated code relies on.
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, parse::Parse, parse_macro_input, spanned::Spanned, DataEnum, DeriveInput,
+    Visibility,
+};
+
+/// returns the predicate and accessors of a variant without fields, which all return `()` if the
+/// variant matches, only the predicate is generated if `is_only` is set
+#[allow(clippy::too_many_arguments)]
+fn unit_fields_return(
+    variant_name: &syn::Ident,
+    pattern: &TokenStream,
+    err_name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics<'_>,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    is_only: bool,
+    (function_name_is, doc_is): (&Ident, &str),
+    (function_name_mut_ref, doc_mut_ref): (&Ident, &str),
+    (function_name_ref, doc_ref): (&Ident, &str),
+    (function_name_val, doc_val): (&Ident, &str),
+) -> Vec<Method> {
+    let variant_str = variant_name.unraw().to_string();
+    let mut methods = vec![attrs.method(
+        doc_is,
+        quote!(fn #function_name_is(&self) -> bool),
+        quote!({ ::core::matches!(self, #pattern) }),
+    )];
+
+    if is_only {
+        return methods;
+    }
+
+    methods.push(attrs.method(
+        doc_ref,
+        quote!(fn #function_name_ref(&self) -> ::core::result::Result<(), #err_name #ty_generics>),
+        quote!({
+            match self {
+                #pattern => {
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                }
+            }
+        }),
+    ));
+
+    methods.push(attrs.method(
+        doc_mut_ref,
+        quote!(fn #function_name_mut_ref(&mut self) -> ::core::result::Result<(), #err_name #ty_generics>),
+        quote!({
+            match self {
+                #pattern => {
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                }
+            }
+        }),
+    ));
+
+    methods.push(attrs.method(
+        doc_val,
+        quote!(fn #function_name_val(self) -> ::core::result::Result<(), #err_name #ty_generics>),
+        quote!({
+            match self {
+                #pattern => {
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(#helpers::__try_as_inner_into_mismatch(self, #variant_str))
+                }
+            }
+        }),
+    ));
+
+    methods
+}
+
+/// returns first the types to return, the match names, and then tokens to the field accesses
+#[allow(clippy::too_many_arguments)]
+fn unnamed_fields_return(
+    variant_name: &syn::Ident,
+    err_name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics<'_>,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    options: &EnumOptions,
+    (function_name_is, doc_is): (&Ident, &str),
+    (function_name_mut_ref, doc_mut_ref): (&Ident, &str),
+    (function_name_ref, doc_ref): (&Ident, &str),
+    (function_name_val, doc_val): (&Ident, &str),
+    fields: &syn::FieldsUnnamed,
+) -> Vec<Method> {
+    let variant_str = variant_name.unraw().to_string();
+    let mut match_names = Vec::new();
+    let (returns_mut_ref, returns_ref, returns_val, matches) = match fields.unnamed.len() {
+        1 => {
+            let field = fields.unnamed.first().expect("no fields on type");
+
+            let returns = &field.ty;
+            let span = returns.span();
+            let returns_mut_ref = quote_spanned!(span=> &mut #returns);
+            let returns_ref = quote_spanned!(span=> &#returns);
+            let returns_val = quote!(#returns);
+            let match_name = Ident::new("inner", span);
+            let matches = match_name.to_token_stream();
+            match_names.push(match_name);
+
+            (returns_mut_ref, returns_ref, returns_val, matches)
+        }
+        _ => {
+            let mut returns_mut_ref = TokenStream::new();
+            let mut returns_ref = TokenStream::new();
+            let mut returns_val = TokenStream::new();
+            let mut matches = TokenStream::new();
+
+            for (i, field) in fields.unnamed.iter().enumerate() {
+                let rt = &field.ty;
+                let span = rt.span();
+                let match_name = Ident::new(&format!("match_{}", i), span);
+                returns_mut_ref.extend(quote_spanned!(span=> &mut #rt,));
+                returns_ref.extend(quote_spanned!(span=> &#rt,));
+                returns_val.extend(quote!(#rt,));
+                matches.extend(quote!(#match_name,));
+                match_names.push(match_name);
+            }
+
+            (
+                quote!((#returns_mut_ref)),
+                quote!((#returns_ref)),
+                quote!((#returns_val)),
+                quote!(#matches),
+            )
+        }
+    };
+
+    let values = |restate: &str| {
+        let values = options.restated(&fields.unnamed, &match_names, restate);
+        quote!(#(#values),*)
+    };
+    let (values_mut_ref, values_ref, values_val) = (
+        values("restate_mut"),
+        values("restate_ref"),
+        values("restate"),
+    );
+
+    vec![
+        attrs.method(
+            doc_is,
+            quote!(fn #function_name_is(&self) -> bool),
+            quote!({
+                ::core::matches!(self, Self::#variant_name(..))
+            }),
+        ),
+        attrs.method(
+            doc_mut_ref,
+            quote!(fn #function_name_mut_ref(&mut self) -> ::core::result::Result<#returns_mut_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name(#matches) => {
+                        ::core::result::Result::Ok((#values_mut_ref))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+        attrs.method(
+            doc_ref,
+            quote!(fn #function_name_ref(&self) -> ::core::result::Result<#returns_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name(#matches) => {
+                        ::core::result::Result::Ok((#values_ref))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+        attrs.method(
+            doc_val,
+            quote!(fn #function_name_val(self) -> ::core::result::Result<#returns_val, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name(#matches) => {
+                        ::core::result::Result::Ok((#values_val))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_into_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+    ]
+}
+
+/// returns first the types to return, the match names, and then tokens to the field accesses
+#[allow(clippy::too_many_arguments)]
+fn named_fields_return(
+    variant_name: &syn::Ident,
+    err_name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics<'_>,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    options: &EnumOptions,
+    (function_name_is, doc_is): (&Ident, &str),
+    (function_name_mut_ref, doc_mut_ref): (&Ident, &str),
+    (function_name_ref, doc_ref): (&Ident, &str),
+    (function_name_val, doc_val): (&Ident, &str),
+    fields: &syn::FieldsNamed,
+) -> Vec<Method> {
+    let variant_str = variant_name.unraw().to_string();
+    let match_names = fields
+        .named
+        .iter()
+        .map(|field| field.ident.clone().expect("expected a named field"))
+        .collect::<Vec<_>>();
+    let (returns_mut_ref, returns_ref, returns_val, matches) = match fields.named.len() {
+        1 => {
+            let field = fields.named.first().expect("no fields on type");
+            let match_name = field.ident.as_ref().expect("expected a named field");
+
+            let returns = &field.ty;
+            let span = returns.span();
+            let returns_mut_ref = quote_spanned!(span=> &mut #returns);
+            let returns_ref = quote_spanned!(span=> &#returns);
+            let returns_val = quote!(#returns);
+            let matches = quote!(#match_name);
+
+            (returns_mut_ref, returns_ref, returns_val, matches)
+        }
+        _ => {
+            let mut returns_mut_ref = TokenStream::new();
+            let mut returns_ref = TokenStream::new();
+            let mut returns_val = TokenStream::new();
+            let mut matches = TokenStream::new();
+
+            for field in fields.named.iter() {
+                let rt = &field.ty;
+                let span = rt.span();
+                let match_name = field.ident.as_ref().expect("expected a named field");
+
+                returns_mut_ref.extend(quote_spanned!(span=> &mut #rt,));
+                returns_ref.extend(quote_spanned!(span=> &#rt,));
+                returns_val.extend(quote!(#rt,));
+                matches.extend(quote!(#match_name,));
+            }
+
+            (
+                quote!((#returns_mut_ref)),
+                quote!((#returns_ref)),
+                quote!((#returns_val)),
+                quote!(#matches),
+            )
+        }
+    };
+
+    let values = |restate: &str| {
+        let values = options.restated(&fields.named, &match_names, restate);
+        quote!(#(#values),*)
+    };
+    let (values_mut_ref, values_ref, values_val) = (
+        values("restate_mut"),
+        values("restate_ref"),
+        values("restate"),
+    );
+
+    vec![
+        attrs.method(
+            doc_is,
+            quote!(fn #function_name_is(&self) -> bool),
+            quote!({
+                ::core::matches!(self, Self::#variant_name { .. })
+            }),
+        ),
+        attrs.method(
+            doc_mut_ref,
+            quote!(fn #function_name_mut_ref(&mut self) -> ::core::result::Result<#returns_mut_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name{ #matches } => {
+                        ::core::result::Result::Ok((#values_mut_ref))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+        attrs.method(
+            doc_ref,
+            quote!(fn #function_name_ref(&self) -> ::core::result::Result<#returns_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name{ #matches } => {
+                        ::core::result::Result::Ok((#values_ref))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+        attrs.method(
+            doc_val,
+            quote!(fn #function_name_val(self) -> ::core::result::Result<#returns_val, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name{ #matches } => {
+                        ::core::result::Result::Ok((#values_val))
+                    }
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_into_mismatch(self, #variant_str))
+                    }
+                }
+            }),
+        ),
+    ]
+}
+
+/// returns the names the fields of a variant are bound to when matching on it
+fn match_names(fields: &syn::Fields) -> Vec<Ident> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None if fields.len() == 1 => Ident::new("inner", field.ty.span()),
+            None => Ident::new(&format!("match_{}", i), field.ty.span()),
+        })
+        .collect()
+}
+
+/// returns the pattern binding the fields of a variant to the match names
+fn fields_pattern(fields: &syn::Fields, matches: &[Ident]) -> TokenStream {
+    match fields {
+        syn::Fields::Unit => quote!(),
+        syn::Fields::Named(_) => quote!({ #(#matches),* }),
+        syn::Fields::Unnamed(_) => quote!(( #(#matches),* )),
+    }
+}
+
+/// returns the fields of a variant constructed from the given values, in the order of the match names
+fn fields_expr(fields: &syn::Fields, matches: &[Ident], values: &[TokenStream]) -> TokenStream {
+    match fields {
+        syn::Fields::Unit => quote!(),
+        syn::Fields::Named(_) => quote!({ #(#matches: #values),* }),
+        syn::Fields::Unnamed(_) => quote!(( #(#values),* )),
+    }
+}
+
+/// returns the pattern matching any value of the variant
+fn variant_pattern(variant: &syn::Variant) -> TokenStream {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Unit => quote!(Self::#variant_name),
+        syn::Fields::Unnamed(_) => quote!(Self::#variant_name(..)),
+        syn::Fields::Named(_) => quote!(Self::#variant_name{..}),
+    }
+}
+
+/// returns the predicates checking the fields of a variant with a closure, by reference and by value
+fn is_and_fields_return(
+    variant_name: &syn::Ident,
+    attrs: &MethodAttrs,
+    options: &EnumOptions,
+    (function_name_and, doc_and): (&Ident, &str),
+    (function_name_and_then, doc_and_then): (&Ident, &str),
+    fields: &syn::Fields,
+) -> Vec<Method> {
+    let matches = match_names(fields);
+    let pattern = fields_pattern(fields, &matches);
+    let (returns_ref, _, returns_val) = variant_returns(fields, None);
+    let values_ref = options.restated(fields, &matches, "restate_ref");
+    let values_val = options.restated(fields, &matches, "restate");
+
+    vec![
+        attrs.method(
+            doc_and,
+            quote!(fn #function_name_and(&self, f: impl ::core::ops::FnOnce(#returns_ref) -> bool) -> bool),
+            quote!({
+                match self {
+                    Self::#variant_name #pattern => f((#(#values_ref),*)),
+                    _ => false,
+                }
+            }),
+        ),
+        attrs.method(
+            doc_and_then,
+            quote!(fn #function_name_and_then(self, f: impl ::core::ops::FnOnce(#returns_val) -> bool) -> bool),
+            quote!({
+                match self {
+                    Self::#variant_name #pattern => f((#(#values_val),*)),
+                    _ => false,
+                }
+            }),
+        ),
+    ]
+}
+
+/// returns the unchecked accessors of a variant, which assume the variant matches and only check it
+/// with a `debug_assert!`
+#[allow(clippy::too_many_arguments)]
+fn unchecked_fields_return(
+    name: &Ident,
+    variant_name: &syn::Ident,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    options: &EnumOptions,
+    (function_name_ref, doc_ref): (&Ident, &str),
+    (function_name_mut_ref, doc_mut_ref): (&Ident, &str),
+    (function_name_val, doc_val): (&Ident, &str),
+    fields: &syn::Fields,
+) -> Vec<Method> {
+    let matches = match_names(fields);
+    let pattern = fields_pattern(fields, &matches);
+    let (returns_ref, returns_mut_ref, returns_val) = variant_returns(fields, None);
+    let safety = |doc: &str| {
+        format!(
+            "{}\n\n # Safety\n\n The enum must be a `{}::{}`, calling this on any other variant is undefined behavior.",
+            doc, name, variant_name,
+        )
+    };
+    let message = format!(
+        "expected {}::{}, but got {}::{{}}",
+        name.unraw(),
+        variant_name.unraw(),
+        name.unraw(),
+    );
+    let body = |restate: &str| {
+        let values = options.restated(fields, &matches, restate);
+        quote!({
+            match self {
+                Self::#variant_name #pattern => (#(#values),*),
+                _ => {
+                    ::core::debug_assert!(false, #message, #helpers::variant_name(&self));
+                    // SAFETY: the caller guarantees that the variant matches
+                    unsafe { ::core::hint::unreachable_unchecked() }
+                }
+            }
+        })
+    };
+
+    vec![
+        attrs.method(
+            &safety(doc_ref),
+            quote!(unsafe fn #function_name_ref(&self) -> #returns_ref),
+            body("restate_ref"),
+        ),
+        attrs.method(
+            &safety(doc_mut_ref),
+            quote!(unsafe fn #function_name_mut_ref(&mut self) -> #returns_mut_ref),
+            body("restate_mut"),
+        ),
+        attrs.method(
+            &safety(doc_val),
+            quote!(unsafe fn #function_name_val(self) -> #returns_val),
+            body("restate"),
+        ),
+    ]
+}
+
+/// returns the accessor copying or cloning the fields of a variant out of `&self`, the fields are
+/// required to be `Copy` or `Clone` through the where clause, which is spanned to their types
+#[allow(clippy::too_many_arguments)]
+fn owned_fields_return(
+    variant_name: &syn::Ident,
+    err_name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics<'_>,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    options: &EnumOptions,
+    (function_name, doc): (&Ident, &str),
+    fields: &syn::Fields,
+    copy: bool,
+) -> Method {
+    let variant_str = variant_name.unraw().to_string();
+    let matches = match_names(fields);
+    let pattern = fields_pattern(fields, &matches);
+    let (_, _, returns_val) = variant_returns(fields, None);
+
+    let mut bounds = Vec::new();
+    let mut values = Vec::new();
+    let values_ref = options.restated(fields, &matches, "restate_ref");
+    for (field, value_ref) in fields.iter().zip(&values_ref) {
+        let ty = &field.ty;
+        if copy {
+            bounds.push(quote_spanned!(ty.span()=> #ty: ::core::marker::Copy));
+            values.push(quote!(*#value_ref));
+        } else {
+            bounds.push(quote_spanned!(ty.span()=> #ty: ::core::clone::Clone));
+            values.push(quote!(::core::clone::Clone::clone(#value_ref)));
+        }
+    }
+
+    attrs.method(
+        doc,
+        quote!(
+            fn #function_name(&self) -> ::core::result::Result<#returns_val, #err_name #ty_generics>
+            where
+                #(#bounds,)*
+        ),
+        quote!({
+            match self {
+                Self::#variant_name #pattern => {
+                    ::core::result::Result::Ok((#(#values),*))
+                }
+                _ => {
+                    ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #variant_str))
+                }
+            }
+        }),
+    )
+}
+
+/// returns the helpers extracting the fields of a variant or falling back to a default, built on the
+/// `try_into_*` and `try_as_*` accessors of the variant, which are called through `callee`
+#[allow(clippy::too_many_arguments)]
+fn or_fields_return(
+    variant: &syn::Variant,
+    name: &Ident,
+    callee: &TokenStream,
+    attrs: &MethodAttrs,
+    function_name_ref: &Ident,
+    function_name_val: &Ident,
+    names: &mut MethodNames,
+) -> syn::Result<Vec<Method>> {
+    let variant_name = &variant.ident;
+    let lifetime: syn::Lifetime = syn::parse_quote!('__r);
+    let (_, _, returns_val) = variant_returns(&variant.fields, None);
+    let (returns_ref, _, _) = variant_returns(&variant.fields, Some(&lifetime));
+
+    // the higher-ranked bound defers the check to the call site, as it is never trivially false
+    let default_bounds = variant.fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote_spanned!(ty.span()=> for<'__d> #ty: ::core::default::Default)
+    });
+
+    let function_name_or = names.variant("", variant, "_or")?;
+    let doc_or = format!(
+        "Returns the inner fields if this is a `{}::{}`, otherwise `default`",
+        name, variant_name,
+    );
+    let function_name_or_else = names.variant("", variant, "_or_else")?;
+    let doc_or_else = format!(
+        "Returns the inner fields if this is a `{}::{}`, otherwise the result of `f`",
+        name, variant_name,
+    );
+    let function_name_or_default = names.variant("", variant, "_or_default")?;
+    let doc_or_default = format!(
+        "Returns the inner fields if this is a `{}::{}`, otherwise their default values",
+        name, variant_name,
+    );
+    let function_name_ref_or = names.variant("", variant, "_ref_or")?;
+    let doc_ref_or = format!(
+        "Returns references to the inner fields if this is a `{}::{}`, otherwise `default`",
+        name, variant_name,
+    );
+
+    Ok(vec![
+        attrs.method(
+            &doc_or,
+            quote!(fn #function_name_or(self, default: #returns_val) -> #returns_val),
+            quote!({
+                ::core::result::Result::unwrap_or(#callee::#function_name_val(self), default)
+            }),
+        ),
+        attrs.method(
+            &doc_or_else,
+            quote!(
+                fn #function_name_or_else<F>(self, f: F) -> #returns_val
+                where
+                    F: ::core::ops::FnOnce() -> #returns_val,
+            ),
+            quote!({
+                ::core::result::Result::unwrap_or_else(#callee::#function_name_val(self), |_| f())
+            }),
+        ),
+        attrs.method(
+            &doc_or_default,
+            quote!(
+                fn #function_name_or_default(self) -> #returns_val
+                where
+                    #(#default_bounds,)*
+            ),
+            quote!({
+                ::core::result::Result::unwrap_or_else(#callee::#function_name_val(self), |_| {
+                    ::core::default::Default::default()
+                })
+            }),
+        ),
+        attrs.method(
+            &doc_ref_or,
+            quote!(fn #function_name_ref_or<#lifetime>(&#lifetime self, default: #returns_ref) -> #returns_ref),
+            quote!({
+                ::core::result::Result::unwrap_or(#callee::#function_name_ref(self), default)
+            }),
+        ),
+    ])
+}
+
+/// returns the `self: Pin<&mut Self>` projection to the fields of a variant, structurally pinned
+/// fields are returned as `Pin<&mut T>` while all others are returned as `&mut T`
+#[allow(clippy::too_many_arguments)]
+fn pin_fields_return(
+    variant_name: &syn::Ident,
+    err_name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics<'_>,
+    helpers: &TokenStream,
+    attrs: &MethodAttrs,
+    (function_name_pin, doc_pin): (&Ident, &str),
+    fields: &syn::Fields,
+    pinned: bool,
+) -> Method {
+    let variant_str = variant_name.unraw().to_string();
+    let mut returns = Vec::new();
+    let mut projections = Vec::new();
+    let matches = match_names(fields);
+
+    for (field, match_name) in fields.iter().zip(&matches) {
+        let rt = &field.ty;
+
+        if pinned {
+            returns.push(quote_spanned!(rt.span()=> ::core::pin::Pin<&mut #rt>));
+            // SAFETY: the field is structurally pinned, `self` is pinned and is never moved out of
+            projections.push(quote!(unsafe { ::core::pin::Pin::new_unchecked(#match_name) }));
+        } else {
+            returns.push(quote_spanned!(rt.span()=> &mut #rt));
+            projections.push(quote!(#match_name));
+        }
+    }
+
+    let pattern = fields_pattern(fields, &matches);
+
+    let (returns, projections) = if returns.len() == 1 {
+        (quote!(#(#returns)*), quote!(#(#projections)*))
+    } else {
+        (quote!((#(#returns),*)), quote!((#(#projections),*)))
+    };
+
+    attrs.method(
+        doc_pin,
+        quote!(
+            fn #function_name_pin(
+                self: ::core::pin::Pin<&mut Self>,
+            ) -> ::core::result::Result<#returns, #err_name #ty_generics>
+        ),
+        quote!({
+            // SAFETY: the returned projections never allow the pinned fields to be moved, the
+            // generated `Unpin` impl and `Drop` guard uphold the remaining pinning invariants
+            let this = unsafe { ::core::pin::Pin::get_unchecked_mut(self) };
+            match this {
+                Self::#variant_name #pattern => {
+                    ::core::result::Result::Ok(#projections)
+                }
+                _ => {
+                    ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(this, #variant_str))
+                }
+            }
+        }),
+    )
+}
+
+/// Returns true if the variant is marked with `#[pin]`
+fn is_pinned(variant: &syn::Variant) -> syn::Result<bool> {
+    let mut pinned = false;
+    for attr in &variant.attrs {
+        if attr.path().is_ident("pin") {
+            attr.meta.require_path_only()?;
+            if variant.fields.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`#[pin]` can only be used on variants with fields",
+                ));
+            }
+            pinned = true;
+        }
+    }
+
+    Ok(pinned)
+}
+
+/// Implements the guards which make the pin projections sound, mirroring `pin-project`:
+///
+/// * the enum is only `Unpin` if all structurally pinned fields are `Unpin`,
+/// * the enum must not implement `Drop`, which could move out of pinned fields.
+fn impl_pin_guards(
+    name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    data: &DataEnum,
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // the origin struct carries the pinned fields, so that it is only `Unpin` if they all are
+    let origin_name = format_ident!("__{}PinOrigin", name);
+    let mut origin_fields = Vec::new();
+    for variant in &data.variants {
+        if is_pinned(variant)? {
+            for field in &variant.fields {
+                let ty = &field.ty;
+                origin_fields.push(quote!(::core::marker::PhantomData<#ty>));
+            }
+        }
+    }
+
+    // every generic parameter must be used by the origin struct, independently of the fields
+    let params = generics.params.iter().filter_map(|param| match param {
+        syn::GenericParam::Type(ty) => {
+            let ident = &ty.ident;
+            Some(quote!(::core::marker::PhantomData<fn() -> #ident>))
+        }
+        syn::GenericParam::Lifetime(lt) => {
+            let lifetime = &lt.lifetime;
+            Some(quote!(::core::marker::PhantomData<&#lifetime ()>))
+        }
+        syn::GenericParam::Const(_) => None,
+    });
+
+    // the additional lifetime makes the `Unpin` bound non-trivial, so it is only evaluated for
+    // concrete uses of the enum
+    let mut origin_generics = generics.clone();
+    origin_generics.params.insert(0, syn::parse_quote!('__pin));
+    let (origin_impl_generics, origin_ty_generics, _) = origin_generics.split_for_impl();
+
+    let unpin_where_clause = where_clause_with(
+        generics,
+        syn::parse_quote!(#origin_name #origin_ty_generics: ::core::marker::Unpin),
+    );
+
+    Ok(quote!(
+        const _: () = {
+            #[allow(dead_code, non_camel_case_types)]
+            #vis struct #origin_name #origin_generics #where_clause {
+                __pin: ::core::marker::PhantomData<&'__pin ()>,
+                __params: (#(#params,)*),
+                __fields: (#(#origin_fields,)*),
+            }
+
+            impl #origin_impl_generics ::core::marker::Unpin for #name #ty_generics
+                #unpin_where_clause
+            {
+            }
+
+            trait MustNotImplDrop {}
+            #[allow(clippy::drop_bounds, drop_bounds)]
+            impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+            impl #impl_generics MustNotImplDrop for #name #ty_generics #where_clause {}
+        };
+    ))
+}
+
+/// A generated method, whose signature and body are kept apart so that it can either be defined in
+/// an inherent impl of the enum, or be declared in the extension trait and defined in its impl
+struct Method {
+    /// the docs and attributes, which belong to the declaration
+    attrs: TokenStream,
+    /// the inline and codegen attributes, which belong to the definition
+    inline: TokenStream,
+    /// the visibility in an inherent impl
+    vis: Visibility,
+    /// the signature, from `fn` up to the end of the where clause
+    sig: TokenStream,
+    /// the body, including its braces
+    body: TokenStream,
+}
+
+impl Method {
+    /// returns the method as an item of an inherent impl
+    fn inherent(&self) -> TokenStream {
+        let Self {
+            attrs,
+            inline,
+            vis,
+            sig,
+            body,
+        } = self;
+        quote!(#attrs #inline #vis #sig #body)
+    }
+
+    /// returns the declaration of the method in a trait
+    fn declaration(&self) -> TokenStream {
+        let Self { attrs, sig, .. } = self;
+        quote!(#attrs #sig;)
+    }
+
+    /// returns the definition of the method in an impl of a trait
+    fn definition(&self) -> TokenStream {
+        let Self {
+            inline, sig, body, ..
+        } = self;
+        quote!(#inline #sig #body)
+    }
+}
+
+/// The attributes and visibility shared by the methods generated for a variant or group
+struct MethodAttrs {
+    attrs: TokenStream,
+    inline: TokenStream,
+    vis: Visibility,
+}
+
+impl MethodAttrs {
+    /// returns the attributes of methods which are not generated for a single variant
+    fn new(options: &EnumOptions) -> Self {
+        Self {
+            attrs: TokenStream::new(),
+            inline: options.inline(),
+            vis: options.vis().clone(),
+        }
+    }
+
+    /// returns a method with the shared attributes below its own docs
+    fn method(&self, doc: &str, sig: TokenStream, body: TokenStream) -> Method {
+        let attrs = &self.attrs;
+        Method {
+            attrs: quote!(
+                #[doc = #doc]
+                #attrs
+            ),
+            inline: self.inline.clone(),
+            vis: self.vis.clone(),
+            sig,
+            body,
+        }
+    }
+}
+
+/// returns the attributes of the methods generated for a variant, which are its docs below
+/// the generated ones, `deprecated` and the attributes of the `attr(...)` options, alongside the
+/// visibility set for the variant
+fn method_attrs(variant: &syn::Variant, options: &EnumOptions) -> syn::Result<MethodAttrs> {
+    let docs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect::<Vec<_>>();
+    let deprecated = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("deprecated"));
+    let variant_options = VariantOptions::parse(variant)?;
+    let attrs = options.attrs.iter().chain(&variant_options.attrs);
+
+    // separate the docs of the variant from the generated ones by an empty line
+    let separator = if docs.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = ""])
+    };
+
+    Ok(MethodAttrs {
+        attrs: quote!(
+            #separator
+            #(#docs)*
+            #(#deprecated)*
+            #(#[#attrs])*
+        ),
+        inline: options.inline(),
+        vis: variant_options.vis.unwrap_or_else(|| options.vis().clone()),
+    })
+}
+
+/// returns the snake_case name of a generated function for the variant
+fn function_name(prefix: &str, variant: &syn::Variant, suffix: &str) -> syn::Result<Ident> {
+    let function_name = format!("{}{}{}", prefix, method_base(variant)?, suffix).to_snake_case();
+    method_ident(&function_name, &method_origin(variant)?)
+}
+
+/// returns the name of the variant used in its generated methods, which is either its own name or
+/// the one set with `#[try_as_inner(rename = "...")]`
+fn method_base(variant: &syn::Variant) -> syn::Result<String> {
+    Ok(match VariantOptions::parse(variant)?.rename {
+        Some(rename) => rename.value(),
+        None => variant.ident.unraw().to_string(),
+    })
+}
+
+/// returns the origin of the names of the generated methods of the variant, which errors about them
+/// are reported on, that is the name set with `#[try_as_inner(rename = "...")]` or the variant
+fn method_origin(variant: &syn::Variant) -> syn::Result<TokenStream> {
+    Ok(match VariantOptions::parse(variant)?.rename {
+        Some(rename) => rename.to_token_stream(),
+        None => variant.ident.to_token_stream(),
+    })
+}
+
+/// returns the identifier of a generated method spanned to the origin of its name, which is escaped
+/// as a raw identifier if it is a keyword, or an error if it is not a valid identifier
+fn method_ident(method_name: &str, origin: &dyn ToTokens) -> syn::Result<Ident> {
+    let invalid = || {
+        syn::Error::new_spanned(
+            origin,
+            format!("`{}` is not a valid method name", method_name),
+        )
+    };
+
+    // keywords are valid raw identifiers, except for the ones referring to paths
+    let mut ident = match syn::parse_str::<Ident>(method_name) {
+        Ok(ident) => ident,
+        Err(_) => match method_name {
+            "_" | "self" | "Self" | "super" | "crate" => return Err(invalid()),
+            _ => syn::parse_str::<Ident>(&format!("r#{}", method_name)).map_err(|_| invalid())?,
+        },
+    };
+
+    // the method points back at its origin, for navigation and diagnostics
+    ident.set_span(origin.span());
+    Ok(ident)
+}
+
+/// The names of the generated methods, alongside the origin of each name and a description of what
+/// it was generated for, which the generators register while emitting the methods
+#[derive(Default)]
+struct MethodNames {
+    methods: Vec<(Ident, TokenStream, String)>,
+    /// the number of helpers at the start of the methods
+    helpers: usize,
+}
+
+impl MethodNames {
+    /// returns the name of a method generated for the variant, and registers it
+    fn variant(
+        &mut self,
+        prefix: &str,
+        variant: &syn::Variant,
+        suffix: &str,
+    ) -> syn::Result<Ident> {
+        let method = function_name(prefix, variant, suffix)?;
+        self.methods.push((
+            method.clone(),
+            method_origin(variant)?,
+            format!("the variant `{}`", variant.ident.unraw()),
+        ));
+        Ok(method)
+    }
+
+    /// returns the name of a method generated for the group, and registers it
+    fn group(&mut self, prefix: &str, group: &syn::LitStr, suffix: &str) -> syn::Result<Ident> {
+        let group_name = group.value().to_snake_case();
+        let method = method_ident(&format!("{}{}{}", prefix, group_name, suffix), group)?;
+        self.methods.push((
+            method.clone(),
+            group.to_token_stream(),
+            format!("the group `{}`", group.value()),
+        ));
+        Ok(method)
+    }
+
+    /// registers a helper method of the enum, helpers are checked first so that collisions with
+    /// them are reported on the variant or group
+    fn helper(&mut self, method: &str, name: &Ident, description: &str) {
+        self.methods.insert(
+            self.helpers,
+            (
+                format_ident!("{}", method),
+                name.to_token_stream(),
+                description.into(),
+            ),
+        );
+        self.helpers += 1;
+    }
+
+    /// checks that no two generated methods have the same name, which rustc would only report as
+    /// duplicate definitions pointing into the expansion
+    fn check(&self) -> syn::Result<()> {
+        for (i, (method, origin, description)) in self.methods.iter().enumerate() {
+            if let Some((_, _, other)) =
+                self.methods[..i].iter().find(|(other, ..)| other == method)
+            {
+                return Err(syn::Error::new_spanned(
+                    origin,
+                    format!(
+                        "the method `{}` of {} collides with the one of {}, \
+                         rename the methods of a variant with `#[try_as_inner(rename = \"...\")]`",
+                        method.unraw(),
+                        description,
+                        other,
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn impl_all_as_fns(
+    name: &Ident,
+    err_name: &Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> syn::Result<Vec<Method>> {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let helpers = options.helpers(quote!(Self), generics);
+    let callee = options.callee(generics);
+
+    let mut methods = Vec::new();
+    for variant_data in &data.variants {
+        let variant_name = &variant_data.ident;
+        let method_attrs = method_attrs(variant_data, options)?;
+        let variant_options = VariantOptions::parse(variant_data)?;
+
+        // only the predicate is generated for variants without fields with `unit_is_only`
+        let is_only = variant_data.fields.is_empty() && options.unit_is_only;
+        let mut accessor_name = |prefix, suffix| {
+            if is_only {
+                function_name(prefix, variant_data, suffix)
+            } else {
+                names.variant(prefix, variant_data, suffix)
+            }
+        };
+
+        let function_name_ref = accessor_name("try_as_", "")?;
+        let doc_ref = format!(
+            "Returns references to the inner fields if this is a `{}::{}`, otherwise an `{}`",
+            name, variant_name, &err_name,
+        );
+        let function_name_mut_ref = accessor_name("try_as_", "_mut")?;
+        let doc_mut_ref = format!(
+            "Returns mutable references to the inner fields if this is a `{}::{}`, otherwise an `{}`",
+            name,
+            variant_name,
+            &err_name,
+        );
+
+        let function_name_val = accessor_name("try_into_", "")?;
+        let doc_val = format!(
+            "Returns the inner fields if this is a `{}::{}`, otherwise returns back the enum in the `Err` case of the result",
+            name,
+            variant_name,
+        );
+
+        let function_name_is = names.variant("is_", variant_data, "")?;
+        let doc_is = format!(
+            "Returns true if this is a `{}::{}`, otherwise false",
+            name, variant_name,
+        );
+
+        methods.extend(match &variant_data.fields {
+            fields if fields.is_empty() => unit_fields_return(
+                variant_name,
+                &variant_pattern(variant_data),
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                is_only,
+                (&function_name_is, &doc_is),
+                (&function_name_mut_ref, &doc_mut_ref),
+                (&function_name_ref, &doc_ref),
+                (&function_name_val, &doc_val),
+            ),
+            syn::Fields::Unit => unreachable!("unit variants have no fields"),
+            syn::Fields::Unnamed(unnamed) => unnamed_fields_return(
+                variant_name,
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                options,
+                (&function_name_is, &doc_is),
+                (&function_name_mut_ref, &doc_mut_ref),
+                (&function_name_ref, &doc_ref),
+                (&function_name_val, &doc_val),
+                unnamed,
+            ),
+            syn::Fields::Named(named) => named_fields_return(
+                variant_name,
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                options,
+                (&function_name_is, &doc_is),
+                (&function_name_mut_ref, &doc_mut_ref),
+                (&function_name_ref, &doc_ref),
+                (&function_name_val, &doc_val),
+                named,
+            ),
+        });
+
+        if (options.is_and || variant_options.is_and) && !variant_data.fields.is_empty() {
+            let function_name_and = names.variant("is_", variant_data, "_and")?;
+            let doc_and = format!(
+                "Returns true if this is a `{}::{}` and its inner fields match the predicate, otherwise false",
+                name, variant_name,
+            );
+            let function_name_and_then = names.variant("is_", variant_data, "_and_then")?;
+            let doc_and_then = format!(
+                "Returns true if this is a `{}::{}` and its inner fields, passed by value, match the predicate, otherwise false",
+                name, variant_name,
+            );
+            methods.extend(is_and_fields_return(
+                variant_name,
+                &method_attrs,
+                options,
+                (&function_name_and, &doc_and),
+                (&function_name_and_then, &doc_and_then),
+                &variant_data.fields,
+            ));
+        }
+
+        if (options.unchecked || variant_options.unchecked) && !variant_data.fields.is_empty() {
+            let function_name_ref = names.variant("as_", variant_data, "_unchecked")?;
+            let doc_ref = format!(
+                "Returns references to the inner fields of a `{}::{}` without checking the variant",
+                name, variant_name,
+            );
+            let function_name_mut_ref = names.variant("as_", variant_data, "_unchecked_mut")?;
+            let doc_mut_ref = format!(
+                "Returns mutable references to the inner fields of a `{}::{}` without checking the variant",
+                name, variant_name,
+            );
+            let function_name_val = names.variant("into_", variant_data, "_unchecked")?;
+            let doc_val = format!(
+                "Returns the inner fields of a `{}::{}` without checking the variant",
+                name, variant_name,
+            );
+            methods.extend(unchecked_fields_return(
+                name,
+                variant_name,
+                &helpers,
+                &method_attrs,
+                options,
+                (&function_name_ref, &doc_ref),
+                (&function_name_mut_ref, &doc_mut_ref),
+                (&function_name_val, &doc_val),
+                &variant_data.fields,
+            ));
+        }
+
+        if (options.get || variant_options.get) && !variant_data.fields.is_empty() {
+            let function_name_get = names.variant("try_get_", variant_data, "")?;
+            let doc_get = format!(
+                "Returns copies of the inner fields if this is a `{}::{}`, otherwise an `{}`",
+                name, variant_name, &err_name,
+            );
+            methods.push(owned_fields_return(
+                variant_name,
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                options,
+                (&function_name_get, &doc_get),
+                &variant_data.fields,
+                true,
+            ));
+        }
+        if (options.cloned || variant_options.cloned) && !variant_data.fields.is_empty() {
+            let function_name_cloned = names.variant("try_cloned_", variant_data, "")?;
+            let doc_cloned = format!(
+                "Returns clones of the inner fields if this is a `{}::{}`, otherwise an `{}`",
+                name, variant_name, &err_name,
+            );
+            methods.push(owned_fields_return(
+                variant_name,
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                options,
+                (&function_name_cloned, &doc_cloned),
+                &variant_data.fields,
+                false,
+            ));
+        }
+
+        if (options.or || variant_options.or) && !variant_data.fields.is_empty() {
+            methods.extend(or_fields_return(
+                variant_data,
+                name,
+                &callee,
+                &method_attrs,
+                &function_name_ref,
+                &function_name_val,
+                names,
+            )?);
+        }
+
+        if options.pin && !variant_data.fields.is_empty() {
+            let function_name_pin = names.variant("try_as_", variant_data, "_pin")?;
+            let pinned = is_pinned(variant_data)?;
+            let doc_pin = if pinned {
+                format!(
+                    "Returns pinned references to the inner fields if this is a `{}::{}`, otherwise an `{}`",
+                    name, variant_name, &err_name,
+                )
+            } else {
+                format!(
+                    "Returns mutable references to the inner fields of a pinned `{}::{}`, otherwise an `{}`",
+                    name, variant_name, &err_name,
+                )
+            };
+
+            methods.push(pin_fields_return(
+                variant_name,
+                err_name,
+                &ty_generics,
+                &helpers,
+                &method_attrs,
+                (&function_name_pin, &doc_pin),
+                &variant_data.fields,
+                pinned,
+            ));
+        }
+    }
+
+    Ok(methods)
+}
+
+/// returns the private helpers shared by the generated methods, which name the variant and build
+/// the errors of mismatches
+fn helper_methods(
+    name: &Ident,
+    err_name: &Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> Vec<Method> {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let helpers = options.helpers(quote!(Self), generics);
+
+    names.helper("variant_name", name, "the helper");
+    for helper in ["__try_as_inner_mismatch", "__try_as_inner_into_mismatch"] {
+        names.helper(helper, name, "the mismatch helpers");
+    }
+
+    let variant_names = data.variants.iter().map(|variant_data| {
+        let pattern = variant_pattern(variant_data);
+        let variant_str = variant_data.ident.unraw().to_string();
+        quote!(#pattern => #variant_str,)
+    });
+    // an uninhabited enum has no variant to name, which the match on the dereferenced value proves
+    // foreign enums may be non-exhaustive, variants added to them later have no known name
+    let variant_name_body = if options.non_exhaustive {
+        quote!(match self {
+            #(#variant_names)*
+            _ => "<unknown>",
+        })
+    } else if data.variants.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self {
+            #(#variant_names)*
+        })
+    };
+
+    let mut methods = vec![
+        Method {
+            attrs: quote!(
+                /// Returns the name of the variant.
+            ),
+            inline: TokenStream::new(),
+            vis: Visibility::Inherited,
+            sig: quote!(fn variant_name(&self) -> &'static str),
+            body: quote!({
+                #variant_name_body
+            }),
+        },
+        Method {
+            attrs: quote!(
+                /// Returns the error of a mismatched variant, shared by all accessors to keep the
+                /// inlined fast path small.
+            ),
+            inline: quote!(
+                #[cold]
+                #[inline(never)]
+            ),
+            vis: Visibility::Inherited,
+            sig: quote!(
+                fn __try_as_inner_mismatch(&self, expected: &'static str) -> #err_name #ty_generics
+            ),
+            body: quote!({
+                #err_name::new(expected, #helpers::variant_name(self), ::core::option::Option::None)
+            }),
+        },
+        Method {
+            attrs: quote!(
+                /// Returns the error of a mismatched variant, which owns the enum.
+            ),
+            inline: quote!(
+                #[cold]
+                #[inline(never)]
+            ),
+            vis: Visibility::Inherited,
+            sig: quote!(
+                fn __try_as_inner_into_mismatch(self, expected: &'static str) -> #err_name #ty_generics
+            ),
+            body: quote!({
+                let actual = #helpers::variant_name(&self);
+                #err_name::new(expected, actual, ::core::option::Option::Some(self))
+            }),
+        },
+    ];
+
+    // the restated shape of a foreign enum is checked once per variant, as the accessors only
+    // cover the variants and fields they are generated for
+    if options.foreign.is_some() {
+        names.helper("__try_as_inner_shape", name, "the shape assertion");
+        let arms = data.variants.iter().map(|variant_data| {
+            let variant_name = &variant_data.ident;
+            let matches = match_names(&variant_data.fields);
+            let pattern = fields_pattern(&variant_data.fields, &matches);
+            let values = options.restated(&variant_data.fields, &matches, "restate_ref");
+            quote!(Self::#variant_name #pattern => { #(let _ = #values;)* })
+        });
+        methods.push(Method {
+            attrs: quote!(
+                /// Checks the restated variants and fields against the foreign enum.
+                #[allow(dead_code)]
+            ),
+            inline: TokenStream::new(),
+            vis: Visibility::Inherited,
+            sig: quote!(fn __try_as_inner_shape(&self)),
+            body: quote!({
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#arms)*
+                    _ => {}
+                }
+            }),
+        });
+    }
+
+    methods
+}
+
+/// the derives of the error which are implemented by hand
+const STD_DERIVES: &[&str] = &[
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "Hash",
+    "PartialOrd",
+    "Ord",
+];
+
+/// Implements one of the standard derives for the error, bounded on the enum implementing the trait,
+/// if the value is ignored the comparisons only consider the names of the variants
+fn impl_err_std_derive(
+    self_path: &syn::Path,
+    err_name: &Ident,
+    generics: &syn::Generics,
+    std_derive: &str,
+    ignore_value: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let (trait_path, body) = match std_derive {
+        "Clone" => (
+            quote!(::core::clone::Clone),
+            quote!(
+                #[inline]
+                fn clone(&self) -> Self {
+                    Self {
+                        expected: self.expected,
+                        actual: self.actual,
+                        value: ::core::clone::Clone::clone(&self.value),
+                    }
+                }
+            ),
+        ),
+        "Copy" => (quote!(::core::marker::Copy), quote!()),
+        "PartialEq" => {
+            let eq_value = if ignore_value {
+                quote!()
+            } else {
+                quote!(&&self.value == other.value)
+            };
+            (
+                quote!(::core::cmp::PartialEq),
+                quote!(
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        self.expected == other.expected && self.actual == other.actual #eq_value
+                    }
+                ),
+            )
+        }
+        "Eq" => (quote!(::core::cmp::Eq), quote!()),
+        "Hash" => {
+            let hash_value = if ignore_value {
+                quote!()
+            } else {
+                quote!(::core::hash::Hash::hash(&self.value, state);)
+            };
+            (
+                quote!(::core::hash::Hash),
+                quote!(
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        ::core::hash::Hash::hash(self.expected, state);
+                        ::core::hash::Hash::hash(self.actual, state);
+                        #hash_value
+                    }
+                ),
+            )
+        }
+        "PartialOrd" => {
+            let cmp_value = if ignore_value {
+                quote!(::core::option::Option::Some(::core::cmp::Ordering::Equal))
+            } else {
+                quote!(::core::cmp::PartialOrd::partial_cmp(
+                    &self.value,
+                    &other.value
+                ))
+            };
+            (
+                quote!(::core::cmp::PartialOrd),
+                quote!(
+                    #[inline]
+                    fn partial_cmp(
+                        &self,
+                        other: &Self,
+                    ) -> ::core::option::Option<::core::cmp::Ordering> {
+                        match ::core::cmp::Ord::cmp(
+                            &(self.expected, self.actual),
+                            &(other.expected, other.actual),
+                        ) {
+                            ::core::cmp::Ordering::Equal => #cmp_value,
+                            ordering => ::core::option::Option::Some(ordering),
+                        }
+                    }
+                ),
+            )
+        }
+        "Ord" => {
+            let cmp_value = if ignore_value {
+                quote!(::core::cmp::Ordering::Equal)
+            } else {
+                quote!(::core::cmp::Ord::cmp(&self.value, &other.value))
+            };
+            (
+                quote!(::core::cmp::Ord),
+                quote!(
+                    #[inline]
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        ::core::cmp::Ord::cmp(
+                            &(self.expected, self.actual),
+                            &(other.expected, other.actual),
+                        )
+                        .then_with(|| #cmp_value)
+                    }
+                ),
+            )
+        }
+        _ => unreachable!("not one of the standard derives"),
+    };
+
+    // the value is always cloned or copied, but only compared if it is not ignored
+    let where_clause = if ignore_value && !matches!(std_derive, "Clone" | "Copy") {
+        generics.where_clause.to_token_stream()
+    } else {
+        where_clause_with(
+            generics,
+            syn::parse_quote!(#self_path #ty_generics: #trait_path),
+        )
+        .to_token_stream()
+    };
+
+    quote!(
+        impl #impl_generics #trait_path for #err_name #ty_generics #where_clause {
+            #body
+        }
+    )
+}
+
+/// returns the infallible `as_*`, `as_*_mut` and `into_*` accessors of a single-variant enum
+fn impl_infallible(
+    name: &Ident,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> syn::Result<Vec<Method>> {
+    let option = match &options.infallible {
+        Some(option) => option,
+        None => return Ok(Vec::new()),
+    };
+
+    let variant_data = match data.variants.first() {
+        Some(variant_data) if data.variants.len() == 1 && !variant_data.fields.is_empty() => {
+            variant_data
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                option,
+                "infallible accessors require an enum with a single variant with fields",
+            ))
+        }
+    };
+
+    let variant_name = &variant_data.ident;
+    let method_attrs = method_attrs(variant_data, options)?;
+
+    let (returns_ref, returns_mut_ref, returns_val) = variant_returns(&variant_data.fields, None);
+    let matches = match_names(&variant_data.fields);
+    let pattern = fields_pattern(&variant_data.fields, &matches);
+    let body = |restate: &str| {
+        let values = options.restated(&variant_data.fields, &matches, restate);
+        quote!({
+            match self {
+                Self::#variant_name #pattern => (#(#values),*),
+            }
+        })
+    };
+
+    let function_name_ref = names.variant("as_", variant_data, "")?;
+    let doc_ref = format!(
+        "Returns references to the inner fields of the only variant `{}::{}`",
+        name, variant_name,
+    );
+    let function_name_mut_ref = names.variant("as_", variant_data, "_mut")?;
+    let doc_mut_ref = format!(
+        "Returns mutable references to the inner fields of the only variant `{}::{}`",
+        name, variant_name,
+    );
+    let function_name_val = names.variant("into_", variant_data, "")?;
+    let doc_val = format!(
+        "Returns the inner fields of the only variant `{}::{}`",
+        name, variant_name,
+    );
+
+    Ok(vec![
+        method_attrs.method(
+            &doc_ref,
+            quote!(fn #function_name_ref(&self) -> #returns_ref),
+            body("restate_ref"),
+        ),
+        method_attrs.method(
+            &doc_mut_ref,
+            quote!(fn #function_name_mut_ref(&mut self) -> #returns_mut_ref),
+            body("restate_mut"),
+        ),
+        method_attrs.method(
+            &doc_val,
+            quote!(fn #function_name_val(self) -> #returns_val),
+            body("restate"),
+        ),
+    ])
+}
+
+/// returns the body of the `Display` impls of the errors, which qualify each expected variant of
+/// a group with the name of the enum
+fn display_body(name: &Ident) -> TokenStream {
+    let name = name.unraw();
+    let variant_fmt = format!("{name}::{{}}");
+    let actual_fmt = format!(", but got {name}::{{}}");
+    quote!(
+        formatter.write_str("expected ")?;
+        for (i, expected) in self.expected.split(" | ").enumerate() {
+            if i > 0 {
+                formatter.write_str(" | ")?;
+            }
+            ::core::write!(formatter, #variant_fmt, expected)?;
+        }
+        ::core::write!(formatter, #actual_fmt, self.actual)
+    )
+}
+
+fn impl_err(
+    name: &Ident,
+    err_name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    attrs: &[syn::Attribute],
+    options: &EnumOptions,
+) -> TokenStream {
+    let self_path = options.self_path(name);
+    let name_str = name.unraw().to_string();
+    let krate = options.krate();
+    let doc_err = format!("An error type for the `{}::try_as_*` functions", name);
+
+    // get the derives for the error type, the standard traits are implemented by hand so that they
+    // are bounded on the enum rather than on its generic parameters
+    let mut derives = Vec::new();
+    let mut derive_debug = false;
+    let mut std_derives = Vec::new();
+    for attr in attrs {
+        if attr.path().is_ident("derive_err") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("Debug") {
+                    derive_debug = true;
+                } else if let Some(std_derive) = STD_DERIVES
+                    .iter()
+                    .find(|std_derive| meta.path.is_ident(std_derive))
+                {
+                    std_derives.push(*std_derive);
+                } else {
+                    derives.push(meta.path);
+                }
+
+                Ok(())
+            })
+            .expect("failed to parse derive nested meta");
+        }
+    }
+
+    let derive_err = if derives.is_empty() {
+        quote!()
+    } else {
+        quote!(#[derive(#(#derives),*)])
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut err_impl = quote!(
+        #[doc = #doc_err ]
+        #derive_err
+        // restated foreign enums need the outlives bounds which their definition implies
+        #[allow(explicit_outlives_requirements)]
+        #vis struct #err_name #generics #where_clause {
+            expected: &'static str,
+            actual: &'static str,
+            value: ::core::option::Option<#self_path #ty_generics>,
+        }
+
+        impl #impl_generics #err_name #ty_generics #where_clause {
+            /// Creates a new error indicating the expected variant and the actual variant.
+            pub fn new(
+                expected: &'static str,
+                actual: &'static str,
+                value: ::core::option::Option<#self_path #ty_generics>
+            ) -> Self {
+                Self {
+                    expected,
+                    actual,
+                    value,
+                }
+            }
+
+            /// Returns the name of the variant that was expected.
+            pub fn expected(&self) -> &'static str {
+                self.expected
+            }
+
+            /// Returns the name of the actual variant.
+            pub fn actual(&self) -> &'static str {
+                self.actual
+            }
+
+            /// Returns a reference to the actual value, if present.
+            pub fn value(&self) -> ::core::option::Option<&#self_path #ty_generics> {
+                self.value.as_ref()
+            }
+
+            /// Returns the actual value, if present.
+            pub fn into_value(self) -> ::core::option::Option<#self_path #ty_generics> {
+                self.value
+            }
+        }
+
+        impl #impl_generics #krate::VariantMismatch for #err_name #ty_generics #where_clause {
+            fn enum_name(&self) -> &'static str {
+                #name_str
+            }
+
+            fn expected(&self) -> &'static str {
+                self.expected
+            }
+
+            fn actual(&self) -> &'static str {
+                self.actual
+            }
+
+            fn has_value(&self) -> bool {
+                self.value.is_some()
+            }
+
+            fn value_any(&self) -> ::core::option::Option<&dyn ::core::any::Any>
+            where
+                Self: 'static,
+            {
+                match &self.value {
+                    ::core::option::Option::Some(value) => ::core::option::Option::Some(value),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+        }
+    );
+
+    for std_derive in std_derives {
+        err_impl.extend(impl_err_std_derive(
+            &self_path,
+            err_name,
+            generics,
+            std_derive,
+            options.err_ignore_value,
+        ));
+    }
+
+    if derive_debug {
+        let impl_debug_body = {
+            let where_clause = where_clause_with(
+                generics,
+                syn::parse_quote!(#self_path #ty_generics: ::core::fmt::Debug),
+            );
+
+            quote!(
+                impl #impl_generics ::core::fmt::Debug for #err_name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.debug_struct(::core::stringify!(#err_name))
+                            .field("expected", &self.expected)
+                            .field("actual", &self.actual)
+                            .field("value", &self.value)
+                            .finish()
+                    }
+                }
+            )
+        };
+
+        let impl_display_body = {
+            let display_body = display_body(name);
+            quote!(
+                impl #impl_generics ::core::fmt::Display for #err_name #ty_generics #where_clause {
+                    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        #display_body
+                    }
+                }
+            )
+        };
+
+        let impl_err_body = {
+            let where_clause = where_clause_with(
+                generics,
+                syn::parse_quote!(#self_path #ty_generics: ::core::fmt::Debug),
+            );
+
+            quote!(
+                impl #impl_generics ::std::error::Error for #err_name #ty_generics #where_clause {}
+            )
+        };
+
+        err_impl.extend(quote!(
+            #impl_debug_body
+
+            #impl_display_body
+
+            #impl_err_body
+        ))
+    }
+
+    err_impl
+}
+
+/// returns the members of every group, in order of appearance
+fn groups(data: &DataEnum) -> syn::Result<Vec<(syn::LitStr, Vec<&syn::Variant>)>> {
+    let mut groups: Vec<(syn::LitStr, Vec<&syn::Variant>)> = Vec::new();
+    for variant_data in &data.variants {
+        for group in VariantOptions::parse(variant_data)?.groups {
+            match groups
+                .iter_mut()
+                .find(|(other, _)| other.value() == group.value())
+            {
+                Some((_, members)) => members.push(variant_data),
+                None => groups.push((group, vec![variant_data])),
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
+/// returns true if the accessors of a group are generated, which is only the case if all members
+/// share the same payload
+fn has_group_accessors(members: &[&syn::Variant]) -> bool {
+    let payload = |variant: &syn::Variant| {
+        variant
+            .fields
+            .iter()
+            .map(|field| field.ty.to_token_stream().to_string())
+            .collect::<Vec<_>>()
+    };
+    let first = members[0];
+    !first.fields.is_empty()
+        && members
+            .iter()
+            .all(|variant| payload(variant) == payload(first))
+}
+
+/// returns the predicates and accessors for the variant groups
+fn impl_groups(
+    name: &Ident,
+    err_name: &Ident,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> syn::Result<Vec<Method>> {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let helpers = options.helpers(quote!(Self), generics);
+    let method_attrs = MethodAttrs::new(options);
+
+    let mut methods = Vec::new();
+    for (group, members) in groups(data)? {
+        let function_name_is = names.group("is_", &group, "")?;
+
+        let patterns = members.iter().map(|variant| variant_pattern(variant));
+        let members_doc = members
+            .iter()
+            .map(|variant| format!("`{}::{}`", name, variant.ident))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let doc_is = format!(
+            "Returns true if this is any of {}, otherwise false",
+            members_doc
+        );
+
+        methods.push(method_attrs.method(
+            &doc_is,
+            quote!(fn #function_name_is(&self) -> bool),
+            quote!({ ::core::matches!(self, #(#patterns)|*) }),
+        ));
+
+        if !has_group_accessors(&members) {
+            continue;
+        }
+        let first = members[0];
+
+        let expected = members
+            .iter()
+            .map(|variant| variant.ident.unraw().to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let (returns_ref, returns_mut_ref, returns_val) = variant_returns(&first.fields, None);
+
+        let arms = |restate: &str| {
+            members
+                .iter()
+                .map(|variant| {
+                    let variant_name = &variant.ident;
+                    let matches = match_names(&variant.fields);
+                    let pattern = fields_pattern(&variant.fields, &matches);
+                    let values = options.restated(&variant.fields, &matches, restate);
+                    quote!(Self::#variant_name #pattern => ::core::result::Result::Ok((#(#values),*)),)
+                })
+                .collect::<TokenStream>()
+        };
+        let (arms_ref, arms_mut_ref, arms_val) =
+            (arms("restate_ref"), arms("restate_mut"), arms("restate"));
+
+        let function_name_ref = names.group("try_as_", &group, "")?;
+        let doc_ref = format!(
+            "Returns references to the inner fields if this is any of {}, otherwise an `{}`",
+            members_doc, err_name,
+        );
+        let function_name_mut_ref = names.group("try_as_", &group, "_mut")?;
+        let doc_mut_ref = format!(
+            "Returns mutable references to the inner fields if this is any of {}, otherwise an `{}`",
+            members_doc, err_name,
+        );
+        let function_name_val = names.group("try_into_", &group, "")?;
+        let doc_val = format!(
+            "Returns the inner fields if this is any of {}, otherwise returns back the enum in the `Err` case of the result",
+            members_doc,
+        );
+
+        methods.push(method_attrs.method(
+            &doc_ref,
+            quote!(fn #function_name_ref(&self) -> ::core::result::Result<#returns_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    #arms_ref
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #expected))
+                    }
+                }
+            }),
+        ));
+        methods.push(method_attrs.method(
+            &doc_mut_ref,
+            quote!(fn #function_name_mut_ref(&mut self) -> ::core::result::Result<#returns_mut_ref, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    #arms_mut_ref
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_mismatch(self, #expected))
+                    }
+                }
+            }),
+        ));
+        methods.push(method_attrs.method(
+            &doc_val,
+            quote!(fn #function_name_val(self) -> ::core::result::Result<#returns_val, #err_name #ty_generics>),
+            quote!({
+                match self {
+                    #arms_val
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_into_mismatch(self, #expected))
+                    }
+                }
+            }),
+        ));
+    }
+
+    Ok(methods)
+}
+
+/// returns the where clause of the generics, extended by the predicate
+fn where_clause_with(generics: &syn::Generics, predicate: syn::WherePredicate) -> syn::WhereClause {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(predicate);
+    generics
+        .where_clause
+        .expect("the where clause was created above")
+}
+
+/// returns true if the identifier appears anywhere in the tokens
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(other) => other == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// returns the tokens with every `Self` replaced
+fn replace_self(tokens: TokenStream, replacement: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => replacement.clone(),
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_self(group.stream(), replacement),
+                );
+                replaced.set_span(group.span());
+                replaced.into_token_stream()
+            }
+            token => token.into_token_stream(),
+        })
+        .collect()
+}
+
+/// returns the generics of the enum restricted to the parameters used by the given fields
+fn subset_generics(generics: &syn::Generics, fields: &TokenStream) -> syn::Generics {
+    let param_ident = |param: &syn::GenericParam| match param {
+        syn::GenericParam::Type(ty) => ty.ident.clone(),
+        syn::GenericParam::Lifetime(lt) => lt.lifetime.ident.clone(),
+        syn::GenericParam::Const(c) => c.ident.clone(),
+    };
+
+    let (used, unused): (Vec<_>, Vec<_>) = generics
+        .params
+        .iter()
+        .cloned()
+        .partition(|param| mentions(fields.clone(), &param_ident(param)));
+
+    // where predicates are only kept if they do not refer to any of the dropped parameters
+    let where_clause = generics.where_clause.as_ref().map(|where_clause| {
+        let mut where_clause = where_clause.clone();
+        where_clause.predicates = where_clause
+            .predicates
+            .into_iter()
+            .filter(|predicate| {
+                unused
+                    .iter()
+                    .all(|param| !mentions(predicate.to_token_stream(), &param_ident(param)))
+            })
+            .collect();
+        where_clause
+    });
+
+    syn::Generics {
+        params: used.into_iter().collect(),
+        where_clause,
+        ..generics.clone()
+    }
+}
+
+/// Implements a subset enum with the given members, with lossless conversions from and to the
+/// enum, returns the generics of the subset alongside
+#[allow(clippy::too_many_arguments)]
+fn impl_subset(
+    self_path: &syn::Path,
+    err_name: &Ident,
+    helpers: &TokenStream,
+    options: &EnumOptions,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    subset_name: &Ident,
+    members: &[&syn::Variant],
+    derives: &[syn::Path],
+    doc_subset: &str,
+) -> (TokenStream, syn::Generics) {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // fields referring to `Self` must keep referring to the enum in the subset
+    let self_ty = quote!(#self_path #ty_generics);
+    let variants = members
+        .iter()
+        .map(|variant| {
+            let docs = variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("deprecated"));
+            let variant_name = &variant.ident;
+            let fields = replace_self(variant.fields.to_token_stream(), &self_ty);
+            quote!(
+                #(#docs)*
+                #variant_name #fields
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let field_types = members
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .map(|field| replace_self(field.ty.to_token_stream(), &self_ty))
+        .collect();
+    let subset_generics = subset_generics(generics, &field_types);
+    let (_, subset_ty_generics, subset_where_clause) = subset_generics.split_for_impl();
+
+    let derive = if derives.is_empty() {
+        quote!()
+    } else {
+        quote!(#[derive(#(#derives),*)])
+    };
+
+    let mut from_arms = TokenStream::new();
+    let mut try_from_arms = TokenStream::new();
+    for variant in members {
+        let variant_name = &variant.ident;
+        let matches = match_names(&variant.fields);
+        let pattern = fields_pattern(&variant.fields, &matches);
+        let restored = fields_expr(
+            &variant.fields,
+            &matches,
+            &options.restated(&variant.fields, &matches, "restore"),
+        );
+        let restated = fields_expr(
+            &variant.fields,
+            &matches,
+            &options.restated(&variant.fields, &matches, "restate"),
+        );
+        from_arms.extend(quote!(
+            #subset_name::#variant_name #pattern => Self::#variant_name #restored,
+        ));
+        try_from_arms.extend(quote!(
+            #self_path::#variant_name #pattern => {
+                ::core::result::Result::Ok(Self::#variant_name #restated)
+            }
+        ));
+    }
+
+    let expected = members
+        .iter()
+        .map(|variant| variant.ident.unraw().to_string())
+        .collect::<Vec<_>>()
+        .join(" | ");
+
+    let tokens = quote!(
+        #[doc = #doc_subset]
+        #derive
+        #vis enum #subset_name #subset_generics #subset_where_clause {
+            #(#variants,)*
+        }
+
+        #[allow(deprecated)]
+        impl #impl_generics ::core::convert::From<#subset_name #subset_ty_generics> for #self_path #ty_generics #where_clause {
+            fn from(value: #subset_name #subset_ty_generics) -> Self {
+                match value {
+                    #from_arms
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl #impl_generics ::core::convert::TryFrom<#self_path #ty_generics> for #subset_name #subset_ty_generics #where_clause {
+            type Error = #err_name #ty_generics;
+
+            fn try_from(value: #self_path #ty_generics) -> ::core::result::Result<Self, #err_name #ty_generics> {
+                match value {
+                    #try_from_arms
+                    _ => {
+                        ::core::result::Result::Err(#helpers::__try_as_inner_into_mismatch(value, #expected))
+                    }
+                }
+            }
+        }
+    );
+
+    (tokens, subset_generics)
+}
+
+/// Implements the subset enums declared on the enum
+fn impl_subsets(
+    name: &Ident,
+    err_name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+) -> syn::Result<TokenStream> {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_path = options.self_path(name);
+    let helpers = options.helpers(quote!(#self_path #ty_generics), generics);
+    let mut stream = TokenStream::new();
+    for subset in &options.subsets {
+        let members = subset
+            .variants
+            .iter()
+            .map(|variant_name| {
+                data.variants
+                    .iter()
+                    .find(|variant| variant.ident == *variant_name)
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            variant_name,
+                            format!("`{}` is not a variant of `{}`", variant_name, name),
+                        )
+                    })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let doc_subset = format!("A subset of the variants of `{}`", name);
+        let (tokens, _) = impl_subset(
+            &self_path,
+            err_name,
+            &helpers,
+            options,
+            vis,
+            generics,
+            &subset.name,
+            &members,
+            &subset.derives,
+            &doc_subset,
+        );
+        stream.extend(tokens);
+    }
+
+    Ok(stream)
+}
+
+/// returns the derives of the remainder of a variant, if a `split_*` function is generated for it
+fn split_derives(
+    variant: &syn::Variant,
+    options: &EnumOptions,
+) -> syn::Result<Option<Vec<syn::Path>>> {
+    Ok(match VariantOptions::parse(variant)?.split {
+        Some(derives) if !derives.is_empty() => Some(derives),
+        Some(_) => Some(options.split.clone().unwrap_or_default()),
+        None => options.split.clone(),
+    })
+}
+
+/// Implements the remainders of the `split_*` functions, and returns the functions, which return the
+/// remaining variants as their own enum if the variant does not match
+fn impl_splits(
+    name: &Ident,
+    err_name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> syn::Result<(TokenStream, Vec<Method>)> {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let self_path = options.self_path(name);
+    let helpers = options.helpers(quote!(#self_path #ty_generics), generics);
+
+    let mut remainders = TokenStream::new();
+    let mut methods = Vec::new();
+    for variant_data in &data.variants {
+        let derives = match split_derives(variant_data, options)? {
+            Some(derives) => derives,
+            None => continue,
+        };
+        let variant_name = &variant_data.ident;
+        let method_attrs = method_attrs(variant_data, options)?;
+        let remainder_name = format_ident!("{}Without{}", name, variant_name);
+        let members = data
+            .variants
+            .iter()
+            .filter(|variant| variant.ident != *variant_name)
+            .collect::<Vec<_>>();
+
+        let doc_remainder = format!(
+            "The variants of `{}` other than `{}::{}`",
+            name, name, variant_name
+        );
+        let (tokens, remainder_generics) = impl_subset(
+            &self_path,
+            err_name,
+            &helpers,
+            options,
+            vis,
+            generics,
+            &remainder_name,
+            &members,
+            &derives,
+            &doc_remainder,
+        );
+        remainders.extend(tokens);
+
+        let (_, remainder_ty_generics, _) = remainder_generics.split_for_impl();
+        let (_, _, returns_val) = variant_returns(&variant_data.fields, None);
+        let matches = match_names(&variant_data.fields);
+        let pattern = fields_pattern(&variant_data.fields, &matches);
+        let values = options.restated(&variant_data.fields, &matches, "restate");
+        let remainder_arms = members.iter().map(|variant| {
+            let variant_name = &variant.ident;
+            let matches = match_names(&variant.fields);
+            let pattern = fields_pattern(&variant.fields, &matches);
+            let restated = fields_expr(
+                &variant.fields,
+                &matches,
+                &options.restated(&variant.fields, &matches, "restate"),
+            );
+            quote!(
+                Self::#variant_name #pattern => {
+                    ::core::result::Result::Err(#remainder_name::#variant_name #restated)
+                }
+            )
+        });
+
+        let function_name_split = names.variant("split_", variant_data, "")?;
+        let doc_split = format!(
+            "Returns the inner fields if this is a `{}::{}`, otherwise returns the remaining variants as a `{}`",
+            name, variant_name, remainder_name,
+        );
+
+        methods.push(method_attrs.method(
+            &doc_split,
+            quote!(fn #function_name_split(self) -> ::core::result::Result<#returns_val, #remainder_name #remainder_ty_generics>),
+            quote!({
+                match self {
+                    Self::#variant_name #pattern => ::core::result::Result::Ok((#(#values),*)),
+                    #(#remainder_arms)*
+                }
+            }),
+        ));
+    }
+
+    Ok((remainders, methods))
+}
+
+/// Implements the detached error, which only holds the names of the variants and can therefore
+/// be `'static + Send + Sync` independently of the enum
+fn impl_mismatch_err(
+    name: &Ident,
+    err_name: &Ident,
+    mismatch_err_name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    options: &EnumOptions,
+) -> TokenStream {
+    let name_str = name.unraw().to_string();
+    let krate = options.krate();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let doc_mismatch_err = format!(
+        "A detached `{}`, holding only the names of the expected and actual variants",
+        err_name
+    );
+    let display_body = display_body(name);
+
+    quote!(
+        #[doc = #doc_mismatch_err ]
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        #vis struct #mismatch_err_name {
+            expected: &'static str,
+            actual: &'static str,
+        }
+
+        impl #mismatch_err_name {
+            /// Returns the name of the variant that was expected.
+            pub fn expected(&self) -> &'static str {
+                self.expected
+            }
+
+            /// Returns the name of the actual variant.
+            pub fn actual(&self) -> &'static str {
+                self.actual
+            }
+        }
+
+        impl ::core::fmt::Display for #mismatch_err_name {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #display_body
+            }
+        }
+
+        impl ::core::error::Error for #mismatch_err_name {}
+
+        impl #krate::VariantMismatch for #mismatch_err_name {
+            fn enum_name(&self) -> &'static str {
+                #name_str
+            }
+
+            fn expected(&self) -> &'static str {
+                self.expected
+            }
+
+            fn actual(&self) -> &'static str {
+                self.actual
+            }
+
+            fn has_value(&self) -> bool {
+                false
+            }
+
+            fn value_any(&self) -> ::core::option::Option<&dyn ::core::any::Any> {
+                ::core::option::Option::None
+            }
+        }
+
+        impl #impl_generics #err_name #ty_generics #where_clause {
+            /// Drops the actual value, returning an error which only holds the names of the
+            /// variants.
+            pub fn into_detached(self) -> #mismatch_err_name {
+                #mismatch_err_name {
+                    expected: self.expected,
+                    actual: self.actual,
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#err_name #ty_generics> for #mismatch_err_name #where_clause {
+            fn from(err: #err_name #ty_generics) -> Self {
+                err.into_detached()
+            }
+        }
+    )
+}
+
+/// returns the reference, mutable reference and value types of the variant's fields, for use in
+/// associated types if a lifetime is given
+fn variant_returns(
+    fields: &syn::Fields,
+    lifetime: Option<&syn::Lifetime>,
+) -> (TokenStream, TokenStream, TokenStream) {
+    match fields {
+        _ if fields.is_empty() => (quote!(()), quote!(()), quote!(())),
+        _ if fields.len() == 1 => {
+            let returns = &fields.iter().next().expect("no fields on type").ty;
+            let span = returns.span();
+            (
+                quote_spanned!(span=> &#lifetime #returns),
+                quote_spanned!(span=> &#lifetime mut #returns),
+                quote!(#returns),
+            )
+        }
+        _ => {
+            let types = fields.iter().map(|field| &field.ty);
+            let returns_ref = types
+                .clone()
+                .map(|ty| quote_spanned!(ty.span()=> &#lifetime #ty,));
+            let returns_mut_ref = types
+                .clone()
+                .map(|ty| quote_spanned!(ty.span()=> &#lifetime mut #ty,));
+            (
+                quote!((#(#returns_ref)*)),
+                quote!((#(#returns_mut_ref)*)),
+                quote!((#(#types,)*)),
+            )
+        }
+    }
+}
+
+/// Implements zero-sized marker types for every variant, and `TryAsVariant` for each of them, and
+/// returns the generic methods built on them
+fn impl_markers(
+    name: &Ident,
+    err_name: &Ident,
+    vis: &Visibility,
+    generics: &syn::Generics,
+    data: &DataEnum,
+    options: &EnumOptions,
+    names: &mut MethodNames,
+) -> syn::Result<(TokenStream, Vec<Method>)> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let self_path = options.self_path(name);
+    let helpers = options.helpers(quote!(Self), generics);
+    let callee = options.callee(generics);
+    let krate = options.krate();
+    let method_attrs = MethodAttrs::new(options);
+    let inline = options.inline();
+    let markers_mod = Ident::new(
+        &format!("{}_variants", name.unraw()).to_snake_case(),
+        Span::call_site(),
+    );
+    let lifetime: syn::Lifetime = syn::parse_quote!('__a);
+
+    let mut markers = TokenStream::new();
+    let mut impls = TokenStream::new();
+    for variant_data in &data.variants {
+        let variant_name = &variant_data.ident;
+        let variant_str = variant_name.unraw().to_string();
+        let doc_marker = format!("Marker type for the `{}::{}` variant", name, variant_name);
+        markers.extend(quote!(
+            #[doc = #doc_marker]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::cmp::PartialOrd,
+                ::core::cmp::Ord,
+                ::core::hash::Hash,
+                ::core::default::Default,
+            )]
+            pub struct #variant_name;
+        ));
+
+        let function_name_is = function_name("is_", variant_data, "")?;
+        let (returns_ref, returns_mut_ref, returns_val) =
+            variant_returns(&variant_data.fields, Some(&lifetime));
+
+        // only the predicate is generated for variants without fields with `unit_is_only`
+        let (try_as_variant, try_as_variant_mut, try_into_variant) =
+            if variant_data.fields.is_empty() && options.unit_is_only {
+                let check = |receiver: TokenStream, mismatch: TokenStream| {
+                    quote!(
+                        if #callee::#function_name_is(#receiver) {
+                            ::core::result::Result::Ok(())
+                        } else {
+                            ::core::result::Result::Err(#helpers::#mismatch(self, #variant_str))
+                        }
+                    )
+                };
+                (
+                    check(quote!(self), quote!(__try_as_inner_mismatch)),
+                    check(quote!(self), quote!(__try_as_inner_mismatch)),
+                    check(quote!(&self), quote!(__try_as_inner_into_mismatch)),
+                )
+            } else {
+                let function_name_ref = function_name("try_as_", variant_data, "")?;
+                let function_name_mut_ref = function_name("try_as_", variant_data, "_mut")?;
+                let function_name_val = function_name("try_into_", variant_data, "")?;
+                (
+                    quote!(#callee::#function_name_ref(self)),
+                    quote!(#callee::#function_name_mut_ref(self)),
+                    quote!(#callee::#function_name_val(self)),
+                )
+            };
+
+        impls.extend(quote!(
+            #[allow(deprecated)]
+            impl #impl_generics #krate::TryAsVariant<#markers_mod::#variant_name> for #self_path #ty_generics #where_clause {
+                type Inner = #returns_val;
+                type Ref<#lifetime> = #returns_ref where Self: #lifetime;
+                type Mut<#lifetime> = #returns_mut_ref where Self: #lifetime;
+                type Error = #err_name #ty_generics;
+
+                #inline
+                fn is_variant(&self) -> bool {
+                    #callee::#function_name_is(self)
+                }
+
+                #inline
+                fn try_as_variant(&self) -> ::core::result::Result<<Self as #krate::TryAsVariant<#markers_mod::#variant_name>>::Ref<'_>, #err_name #ty_generics> {
+                    #try_as_variant
+                }
+
+                #inline
+                fn try_as_variant_mut(&mut self) -> ::core::result::Result<<Self as #krate::TryAsVariant<#markers_mod::#variant_name>>::Mut<'_>, #err_name #ty_generics> {
+                    #try_as_variant_mut
+                }
+
+                #inline
+                fn try_into_variant(self) -> ::core::result::Result<#returns_val, #err_name #ty_generics> {
+                    #try_into_variant
+                }
+            }
+        ));
+    }
+
+    for helper in ["is", "try_as", "try_as_mut", "try_into"] {
+        names.helper(helper, name, "the marker helpers");
+    }
+
+    let doc_mod = format!("Marker types for the variants of `{}`", name);
+
+    let tokens = quote!(
+        #[doc = #doc_mod]
+        #[allow(non_camel_case_types)]
+        #vis mod #markers_mod {
+            #markers
+        }
+
+        #impls
+    );
+
+    let methods = vec![
+        method_attrs.method(
+            "Returns true if this is the variant `V`, otherwise false",
+            quote!(
+                fn is<V>(&self) -> bool
+                where
+                    Self: #krate::TryAsVariant<V>,
+            ),
+            quote!({
+                #krate::TryAsVariant::<V>::is_variant(self)
+            }),
+        ),
+        method_attrs.method(
+            "Returns references to the inner fields if this is the variant `V`, otherwise an error",
+            quote!(
+                fn try_as<V>(
+                    &self,
+                ) -> ::core::result::Result<
+                    <Self as #krate::TryAsVariant<V>>::Ref<'_>,
+                    <Self as #krate::TryAsVariant<V>>::Error,
+                >
+                where
+                    Self: #krate::TryAsVariant<V>,
+            ),
+            quote!({
+                #krate::TryAsVariant::<V>::try_as_variant(self)
+            }),
+        ),
+        method_attrs.method(
+            "Returns mutable references to the inner fields if this is the variant `V`, otherwise an error",
+            quote!(
+                fn try_as_mut<V>(
+                    &mut self,
+                ) -> ::core::result::Result<
+                    <Self as #krate::TryAsVariant<V>>::Mut<'_>,
+                    <Self as #krate::TryAsVariant<V>>::Error,
+                >
+                where
+                    Self: #krate::TryAsVariant<V>,
+            ),
+            quote!({
+                #krate::TryAsVariant::<V>::try_as_variant_mut(self)
+            }),
+        ),
+        method_attrs.method(
+            "Returns the inner fields if this is the variant `V`, otherwise returns back the enum in the `Err` case of the result",
+            quote!(
+                fn try_into<V>(
+                    self,
+                ) -> ::core::result::Result<
+                    <Self as #krate::TryAsVariant<V>>::Inner,
+                    <Self as #krate::TryAsVariant<V>>::Error,
+                >
+                where
+                    Self: #krate::TryAsVariant<V>,
+            ),
+            quote!({
+                #krate::TryAsVariant::<V>::try_into_variant(self)
+            }),
+        ),
+    ];
+
+    Ok((tokens, methods))
+}
+
+/// Options set through `#[try_as_inner(...)]` attributes on the enum
+#[derive(Default)]
+struct EnumOptions {
+    /// generate `try_as_*_pin` projections
+    pin: bool,
+    /// generate marker types and `TryAsVariant` impls for every variant
+    markers: bool,
+    /// path to the `enum_try_as_inner` crate
+    krate: Option<syn::Path>,
+    /// generate the detached `{Name}MismatchError` and `into_detached`
+    detached: bool,
+    /// subset enums to generate
+    subsets: Vec<Subset>,
+    /// generate `split_*` functions for every variant, with the derives of the remainders
+    split: Option<Vec<syn::Path>>,
+    /// attributes attached to the methods of every variant
+    attrs: Vec<syn::Meta>,
+    /// visibility of the generated methods, defaults to the visibility of the enum
+    vis: Option<Visibility>,
+    /// visibility of the generated error types, defaults to the visibility of the enum
+    err_vis: Option<Visibility>,
+    /// only compare and hash the variant names of the error, ignoring the value
+    err_ignore_value: bool,
+    /// generate infallible accessors for the only variant of the enum
+    infallible: Option<syn::Path>,
+    /// only generate `is_*` for the variants without fields
+    unit_is_only: bool,
+    /// generate `try_get_*` copying the fields of every variant
+    get: bool,
+    /// generate `try_cloned_*` cloning the fields of every variant
+    cloned: bool,
+    /// generate `*_or`, `*_or_else`, `*_or_default` and `*_ref_or` for every variant
+    or: bool,
+    /// generate `is_*_and` and `is_*_and_then` for every variant
+    is_and: bool,
+    /// generate the unsafe `as_*_unchecked` accessors for every variant
+    unchecked: bool,
+    /// the inline attribute of the generated methods, `#[inline]` if not set
+    inline: Option<syn::LitStr>,
+    /// generate the methods as an extension trait with this name instead of an inherent impl
+    trait_name: Option<Ident>,
+    /// visibility of the extension trait, defaults to the visibility of the methods
+    trait_vis: Option<Visibility>,
+    /// path to the foreign enum the methods are generated for
+    foreign: Option<syn::Path>,
+    /// the foreign enum is `#[non_exhaustive]`
+    non_exhaustive: bool,
+}
+
+/// A subset of the variants of the enum, generated as its own enum
+struct Subset {
+    name: Ident,
+    variants: Vec<Ident>,
+    derives: Vec<syn::Path>,
+}
+
+impl EnumOptions {
+    fn parse(attrs: &[syn::Attribute], vis: &Visibility, data: &DataEnum) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("try_as_inner") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("pin") {
+                        options.pin = true;
+                        Ok(())
+                    } else if meta.path.is_ident("markers") {
+                        options.markers = true;
+                        Ok(())
+                    } else if meta.path.is_ident("trait") {
+                        let trait_name: syn::LitStr = meta.value()?.parse()?;
+                        options.trait_name = Some(trait_name.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("trait_vis") {
+                        options.trait_vis = Some(parse_vis(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("crate") {
+                        let path: syn::LitStr = meta.value()?.parse()?;
+                        options.krate = Some(path.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("detached") {
+                        options.detached = true;
+                        Ok(())
+                    } else if meta.path.is_ident("split") {
+                        options.split = Some(parse_split(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("attr") {
+                        options.attrs.extend(parse_attrs(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("vis") {
+                        options.vis = Some(parse_vis(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("err_vis") {
+                        options.err_vis = Some(parse_vis(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("err_ignore_value") {
+                        options.err_ignore_value = true;
+                        Ok(())
+                    } else if meta.path.is_ident("unit_is_only") {
+                        options.unit_is_only = true;
+                        Ok(())
+                    } else if meta.path.is_ident("get") {
+                        options.get = true;
+                        Ok(())
+                    } else if meta.path.is_ident("cloned") {
+                        options.cloned = true;
+                        Ok(())
+                    } else if meta.path.is_ident("or") {
+                        options.or = true;
+                        Ok(())
+                    } else if meta.path.is_ident("is_and") {
+                        options.is_and = true;
+                        Ok(())
+                    } else if meta.path.is_ident("unchecked") {
+                        options.unchecked = true;
+                        Ok(())
+                    } else if meta.path.is_ident("inline") {
+                        let inline: syn::LitStr = meta.value()?.parse()?;
+                        match inline.value().as_str() {
+                            "hint" | "always" | "never" | "none" => {
+                                options.inline = Some(inline);
+                                Ok(())
+                            }
+                            _ => Err(syn::Error::new_spanned(
+                                inline,
+                                "expected one of \"hint\", \"always\", \"never\" or \"none\"",
+                            )),
+                        }
+                    } else if meta.path.is_ident("infallible") {
+                        options.infallible = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("subset") {
+                        let mut subsets = Vec::new();
+                        let mut derives = Vec::new();
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("derive") {
+                                meta.parse_nested_meta(|meta| {
+                                    derives.push(meta.path);
+                                    Ok(())
+                                })
+                            } else {
+                                let name = meta.path.require_ident()?.clone();
+                                let value = meta.value()?;
+                                let content;
+                                syn::bracketed!(content in value);
+                                let variants = content
+                                    .parse_terminated(Ident::parse, syn::Token![,])?
+                                    .into_iter()
+                                    .collect();
+                                subsets.push((name, variants));
+                                Ok(())
+                            }
+                        })?;
+
+                        // the derives apply to all subsets declared alongside them
+                        options
+                            .subsets
+                            .extend(subsets.into_iter().map(|(name, variants)| Subset {
+                                name,
+                                variants,
+                                derives: derives.clone(),
+                            }));
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported try_as_inner option"))
+                    }
+                })?;
+            }
+        }
+
+        // marking any variant with `#[pin]` opts into the pin projections as well
+        for variant in &data.variants {
+            options.pin |= is_pinned(variant)?;
+        }
+
+        options.vis.get_or_insert_with(|| vis.clone());
+        options.err_vis.get_or_insert_with(|| vis.clone());
+
+        Ok(options)
+    }
+
+    /// returns the visibility of the generated methods
+    fn vis(&self) -> &Visibility {
+        self.vis.as_ref().expect("visibility is set when parsing")
+    }
+
+    /// returns the visibility of the generated error types
+    fn err_vis(&self) -> &Visibility {
+        self.err_vis
+            .as_ref()
+            .expect("visibility is set when parsing")
+    }
+
+    /// returns the path to the enum, which is the foreign enum if set
+    fn self_path(&self, name: &Ident) -> syn::Path {
+        self.foreign.clone().unwrap_or_else(|| name.clone().into())
+    }
+
+    /// returns the fields bound to `matches` as values of their restated types, for foreign enums
+    /// they are passed through `restate` of `Same`, spanned to the restated types so that a
+    /// mismatch with the actual types is reported once at the restated field
+    fn restated<'a>(
+        &self,
+        fields: impl IntoIterator<Item = &'a syn::Field>,
+        matches: &[Ident],
+        restate: &str,
+    ) -> Vec<TokenStream> {
+        let krate = self.krate();
+        let restate = Ident::new(restate, Span::call_site());
+        fields
+            .into_iter()
+            .zip(matches)
+            .map(|(field, match_name)| {
+                let ty = &field.ty;
+                if self.foreign.is_some() {
+                    quote_spanned!(ty.span()=> <_ as #krate::__private::Same<#ty>>::#restate(#match_name))
+                } else {
+                    quote!(#match_name)
+                }
+            })
+            .collect()
+    }
+
+    /// returns the visibility of the extension trait
+    fn trait_vis(&self) -> &Visibility {
+        self.trait_vis.as_ref().unwrap_or_else(|| self.vis())
+    }
+
+    /// returns the type through which the generated methods call each other, the extension trait
+    /// if set so inherent methods of the same name do not shadow them
+    fn callee(&self, generics: &syn::Generics) -> TokenStream {
+        match &self.trait_name {
+            Some(trait_name) => {
+                let (_, ty_generics, _) = generics.split_for_impl();
+                quote!(<Self as #trait_name #ty_generics>)
+            }
+            None => quote!(Self),
+        }
+    }
+
+    /// returns the type through which the generated code calls the private helpers on `self_ty`,
+    /// the helper trait if the methods are generated as an extension trait
+    fn helpers(&self, self_ty: TokenStream, generics: &syn::Generics) -> TokenStream {
+        match &self.trait_name {
+            Some(trait_name) => {
+                let helpers_name = helpers_name(trait_name);
+                let (_, ty_generics, _) = generics.split_for_impl();
+                quote!(<#self_ty as #helpers_name #ty_generics>)
+            }
+            None => quote!(<#self_ty>),
+        }
+    }
+
+    /// returns the inline attribute of the generated methods
+    fn inline(&self) -> TokenStream {
+        match self.inline.as_ref().map(syn::LitStr::value).as_deref() {
+            Some("always") => quote!(#[inline(always)]),
+            Some("never") => quote!(#[inline(never)]),
+            Some("none") => quote!(),
+            _ => quote!(#[inline]),
+        }
+    }
+
+    /// returns the path to the `enum_try_as_inner` crate
+    fn krate(&self) -> syn::Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::enum_try_as_inner))
+    }
+}
+
+/// parses the derives of the `split` option, `split` or `split(derive(...))`
+fn parse_split(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Vec<syn::Path>> {
+    let mut derives = Vec::new();
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|meta| {
+                    derives.push(meta.path);
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unsupported split option"))
+            }
+        })?;
+    }
+
+    Ok(derives)
+}
+
+/// parses the visibility of the `vis = "..."` options
+fn parse_vis(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Visibility> {
+    let vis: syn::LitStr = meta.value()?.parse()?;
+    vis.parse()
+}
+
+/// parses the attributes of the `attr(...)` option
+fn parse_attrs(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Vec<syn::Meta>> {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    Ok(content
+        .parse_terminated(syn::Meta::parse, syn::Token![,])?
+        .into_iter()
+        .collect())
+}
+
+/// Options set through `#[try_as_inner(...)]` attributes on a variant
+#[derive(Default)]
+struct VariantOptions {
+    /// the groups the variant is a member of
+    groups: Vec<syn::LitStr>,
+    /// generate a `split_*` function, with the derives of the remainder
+    split: Option<Vec<syn::Path>>,
+    /// attributes attached to the methods of the variant
+    attrs: Vec<syn::Meta>,
+    /// visibility of the methods of the variant, defaults to the one of the enum
+    vis: Option<Visibility>,
+    /// the name of the variant used in its generated methods
+    rename: Option<syn::LitStr>,
+    /// generate `try_get_*` copying the fields of the variant
+    get: bool,
+    /// generate `try_cloned_*` cloning the fields of the variant
+    cloned: bool,
+    /// generate `*_or`, `*_or_else`, `*_or_default` and `*_ref_or` for the variant
+    or: bool,
+    /// generate `is_*_and` and `is_*_and_then` for the variant
+    is_and: bool,
+    /// generate the unsafe `as_*_unchecked` accessors for the variant
+    unchecked: bool,
+}
+
+impl VariantOptions {
+    fn parse(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in &variant.attrs {
+            if attr.path().is_ident("try_as_inner") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("group") {
+                        options.groups.push(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("split") {
+                        options.split = Some(parse_split(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("attr") {
+                        options.attrs.extend(parse_attrs(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("vis") {
+                        options.vis = Some(parse_vis(&meta)?);
+                        Ok(())
+                    } else if meta.path.is_ident("rename") {
+                        options.rename = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("get") {
+                        options.get = true;
+                        Ok(())
+                    } else if meta.path.is_ident("cloned") {
+                        options.cloned = true;
+                        Ok(())
+                    } else if meta.path.is_ident("or") {
+                        options.or = true;
+                        Ok(())
+                    } else if meta.path.is_ident("is_and") {
+                        options.is_and = true;
+                        Ok(())
+                    } else if meta.path.is_ident("unchecked") {
+                        options.unchecked = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported try_as_inner variant option"))
+                    }
+                })?;
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// returns the name of the private trait holding the helpers of the extension trait
+fn helpers_name(trait_name: &Ident) -> Ident {
+    format_ident!("__{}Helpers", trait_name)
+}
+
+/// Implements the generated methods and the private helpers, either in an inherent impl or as an
+/// extension trait with the helpers in a private trait
+fn impl_methods(
+    name: &Ident,
+    generics: &syn::Generics,
+    options: &EnumOptions,
+    methods: &[Method],
+    helpers: &[Method],
+) -> TokenStream {
+    let self_path = options.self_path(name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let trait_name = if let Some(trait_name) = &options.trait_name {
+        trait_name
+    } else {
+        let methods = methods.iter().chain(helpers).map(Method::inherent);
+        return quote!(
+            #[allow(deprecated)]
+            impl #impl_generics #self_path #ty_generics #where_clause {
+                #(#methods)*
+            }
+        );
+    };
+
+    let trait_vis = options.trait_vis();
+    let helpers_name = helpers_name(trait_name);
+    let doc_trait = format!("Accessors for the variants of `{}`", name);
+    let declarations = methods.iter().map(Method::declaration);
+    let definitions = methods.iter().map(Method::definition);
+    let helper_declarations = helpers.iter().map(Method::declaration);
+    let helper_definitions = helpers.iter().map(Method::definition);
+
+    quote!(
+        #[doc = #doc_trait]
+        #[allow(deprecated)]
+        #trait_vis trait #trait_name #impl_generics #where_clause {
+            #(#declarations)*
+        }
+
+        #[allow(deprecated)]
+        impl #impl_generics #trait_name #ty_generics for #self_path #ty_generics #where_clause {
+            #(#definitions)*
+        }
+
+        trait #helpers_name #impl_generics #where_clause {
+            #(#helper_declarations)*
+        }
+
+        impl #impl_generics #helpers_name #ty_generics for #self_path #ty_generics #where_clause {
+            #(#helper_definitions)*
+        }
+    )
+}
+
+fn expand(ast: &DeriveInput, foreign: Option<&syn::Path>) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let err_name = format_ident!("{}Error", name);
+    let mismatch_err_name = format_ident!("{}MismatchError", name);
+    let generics = &ast.generics;
+    let vis = &ast.vis;
+
+    let enum_data = if let syn::Data::Enum(data) = &ast.data {
+        data
+    } else {
+        panic!("{} is not an enum", name);
+    };
+
+    let mut options = EnumOptions::parse(&ast.attrs, vis, enum_data)?;
+    if let Some(path) = foreign {
+        // the layout of a foreign enum can not be guaranteed by the pin guards
+        if options.pin {
+            return Err(syn::Error::new_spanned(
+                path,
+                "pin projections are not supported for foreign enums",
+            ));
+        }
+
+        options.foreign = Some(path.clone());
+        options.non_exhaustive = ast
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("non_exhaustive"));
+        options
+            .trait_name
+            .get_or_insert_with(|| format_ident!("{}Ext", name));
+    }
+
+    let mut expanded = TokenStream::new();
+    let mut names = MethodNames::default();
+
+    // a trait has no visibility per method
+    if options.trait_name.is_some() {
+        for variant_data in &enum_data.variants {
+            if let Some(vis) = VariantOptions::parse(variant_data)?.vis {
+                return Err(syn::Error::new_spanned(
+                    vis,
+                    "`vis` can not be set for a variant when the methods are generated as a trait",
+                ));
+            }
+        }
+    }
+
+    // Build the methods
+    let mut methods = impl_all_as_fns(name, &err_name, generics, enum_data, &options, &mut names)?;
+    let helpers = helper_methods(name, &err_name, generics, enum_data, &options, &mut names);
+
+    // Build the error
+    let err = impl_err(
+        name,
+        &err_name,
+        options.err_vis(),
+        generics,
+        &ast.attrs,
+        &options,
+    );
+
+    // Build the detached error
+    let mismatch_err = if options.detached {
+        impl_mismatch_err(
+            name,
+            &err_name,
+            &mismatch_err_name,
+            options.err_vis(),
+            generics,
+            &options,
+        )
+    } else {
+        TokenStream::new()
+    };
+
+    // Build the infallible accessors
+    methods.extend(impl_infallible(name, enum_data, &options, &mut names)?);
+
+    // Build the group accessors
+    methods.extend(impl_groups(
+        name, &err_name, generics, enum_data, &options, &mut names,
+    )?);
+
+    // Build the subset enums
+    let subsets = impl_subsets(name, &err_name, vis, generics, enum_data, &options)?;
+
+    // Build the split functions and their remainders
+    let (remainders, splits) = impl_splits(
+        name, &err_name, vis, generics, enum_data, &options, &mut names,
+    )?;
+    methods.extend(splits);
+
+    expanded.extend(err);
+    expanded.extend(subsets);
+    expanded.extend(remainders);
+    expanded.extend(mismatch_err);
+
+    if options.pin {
+        expanded.extend(impl_pin_guards(name, vis, generics, enum_data)?);
+    }
+
+    if options.markers {
+        let (markers, marker_methods) = impl_markers(
+            name, &err_name, vis, generics, enum_data, &options, &mut names,
+        )?;
+        expanded.extend(markers);
+        methods.extend(marker_methods);
+    }
+
+    expanded.extend(impl_methods(name, generics, &options, &methods, &helpers));
+
+    names.check()?;
+
+    Ok(expanded)
+}
+
+/// Derive functions on an Enum for easily accessing individual items in the Enum
+#[proc_macro_derive(EnumTryAsInner, attributes(derive_err, try_as_inner, pin))]
+pub fn enum_try_as_inner(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    // get a usable token stream
+    let ast: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    expand(&ast, None)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The restated shape of a foreign enum, `enum path::to::Name { .. }` with the attributes of the derive
+struct ForeignEnum {
+    path: syn::Path,
+    ast: DeriveInput,
+}
+
+impl Parse for ForeignEnum {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let enum_token = input.parse()?;
+        let path = input.call(syn::Path::parse_mod_style)?;
+        let ident = path
+            .segments
+            .last()
+            .expect("a path has at least one segment")
+            .ident
+            .clone();
+        let mut generics: syn::Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+
+        let content;
+        let brace_token = syn::braced!(content in input);
+        let variants = content.parse_terminated(syn::Variant::parse, syn::Token![,])?;
+
+        Ok(Self {
+            path,
+            ast: DeriveInput {
+                attrs,
+                vis,
+                ident,
+                generics,
+                data: syn::Data::Enum(DataEnum {
+                    enum_token,
+                    brace_token,
+                    variants,
+                }),
+            },
+        })
+    }
+}
+
+/// Generate the functions of the derive for an enum of another crate, as an extension trait
+///
+/// The shape of the enum is restated with its path, any mismatch with the actual enum fails to compile.
+#[proc_macro]
+pub fn foreign_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let foreign: ForeignEnum = parse_macro_input!(input as ForeignEnum);
+
+    expand(&foreign.ast, Some(&foreign.path))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// returns the attributes in front of the generated method
+    fn method_attrs_of(expanded: &str, method: &str) -> String {
+        let method = expanded
+            .find(&format!(" fn {} (", method))
+            .expect("method not generated");
+        let start = expanded[..method]
+            .rfind(['{', '}'])
+            .map_or(0, |end| end + 1);
+        expanded[start..method].to_string()
+    }
+
+    #[test]
+    fn test_forwarded_docs() {
+        let ast: DeriveInput = syn::parse_quote!(
+            enum Config {
+                /// A configuration read from a file.
+                File(String),
+                /// The configuration of older versions.
+                #[deprecated(note = "use `Config::File` instead")]
+                Legacy(u32),
+                Default,
+            }
+        );
+        let expanded = expand(&ast, None).unwrap().to_string();
+
+        for method in ["is_file", "try_as_file", "try_as_file_mut", "try_into_file"] {
+            let attrs = method_attrs_of(&expanded, method);
+            assert!(attrs.contains("\" A configuration read from a file.\""));
+            assert!(!attrs.contains("deprecated"));
+        }
+
+        for method in ["is_legacy", "try_as_legacy", "try_into_legacy"] {
+            let attrs = method_attrs_of(&expanded, method);
+            assert!(attrs.contains("\" The configuration of older versions.\""));
+            assert!(attrs.contains("deprecated (note = \"use `Config::File` instead\")"));
+        }
+
+        let attrs = method_attrs_of(&expanded, "is_default");
+        assert!(!attrs.contains("A configuration") && !attrs.contains("older versions"));
+    }
+}
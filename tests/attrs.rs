@@ -0,0 +1,63 @@
+//! Tests for the attributes forwarded to the generated methods.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, split, attr(doc(alias = "try_as_any")))]
+enum Config {
+    /// A configuration read from a file.
+    #[try_as_inner(attr(must_use, cfg_attr(test, doc(alias = "try_as_path"))))]
+    File(String),
+    /// The configuration of older versions.
+    #[deprecated(note = "use `Config::File` instead")]
+    Legacy(u32),
+    Default,
+}
+
+#[test]
+fn test_forwarded() {
+    assert!(Config::File("config.toml".into()).is_file());
+    assert_eq!(
+        Config::File("config.toml".into()).try_into_file().unwrap(),
+        "config.toml"
+    );
+    assert!(Config::Default.is::<config_variants::Default>());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated() {
+    let legacy = Config::Legacy(1);
+
+    assert!(legacy.is_legacy());
+    assert_eq!(*legacy.try_as_legacy().unwrap(), 1);
+    assert!(matches!(legacy.split_legacy(), std::result::Result::Ok(1)));
+}
@@ -0,0 +1,107 @@
+//! Tests for variants which are conditionally compiled.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use std::convert::TryFrom;
+use std::pin::Pin;
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+// `test` is always enabled for the tests, the disabled variants refer to types which do not exist
+// to make sure nothing referring to them is generated. rustc strips the disabled variants, including
+// those disabled through `cfg_attr`, before the derive sees the enum.
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, split, subset(Numbers = [Int, Float]))]
+enum Value {
+    #[try_as_inner(group = "number")]
+    Int(i64),
+    #[try_as_inner(group = "number")]
+    #[cfg(test)]
+    Float(f64),
+    #[try_as_inner(group = "number")]
+    #[cfg(not(test))]
+    Big(NonExistentType),
+    #[cfg_attr(not(test), cfg(not(test)))]
+    Str(String),
+    #[cfg_attr(test, cfg(not(test)))]
+    Missing { value: NonExistentType },
+}
+
+#[test]
+fn test_enabled() {
+    assert!(Value::Float(1.0).is_float());
+    assert_eq!(*Value::Float(1.0).try_as_float().unwrap(), 1.0);
+    assert_eq!(Value::Str("str".into()).try_into_str().unwrap(), "str");
+    assert!(!Value::Int(1).is::<value_variants::Float>());
+}
+
+#[test]
+fn test_disabled() {
+    let err = Value::Str("str".into()).try_into_int().unwrap_err();
+    assert_eq!(err.actual(), "Str");
+}
+
+#[test]
+fn test_groups() {
+    assert!(Value::Float(1.0).is_number());
+    assert!(!Value::Str(String::new()).is_number());
+}
+
+#[test]
+fn test_subset_and_split() {
+    assert!(matches!(
+        Numbers::try_from(Value::Float(1.0)).unwrap(),
+        Numbers::Float(_)
+    ));
+    assert_eq!(Value::from(Numbers::Int(1)), Value::Int(1));
+
+    match Value::Int(1).split_float().unwrap_err() {
+        ValueWithoutFloat::Int(value) => assert_eq!(value, 1),
+        ValueWithoutFloat::Str(_) => panic!("expected an int"),
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Task<F> {
+    #[pin]
+    Running(F),
+    #[pin]
+    #[cfg(not(test))]
+    Blocked(NonExistentType),
+    Done,
+}
+
+#[test]
+fn test_pin() {
+    let mut task = Task::Running(1_u32);
+    let task = Pin::new(&mut task);
+    assert_eq!(*task.try_as_running_pin().unwrap(), 1);
+}
@@ -12,6 +12,11 @@
     unreachable_pub
 )]
 
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
 use enum_try_as_inner::EnumTryAsInner;
 
 pub mod name_collisions {
@@ -45,3 +50,82 @@ fn test_derive_err() {
         two.try_into_one().unwrap_err()
     );
 }
+
+/// Does not implement any traits
+#[derive(Debug)]
+struct NotClone;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug, Clone)]
+enum Shared<T> {
+    Rc(Rc<T>),
+    Empty,
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Rc(rc) => Self::Rc(Rc::clone(rc)),
+            Self::Empty => Self::Empty,
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, EnumTryAsInner)]
+#[derive_err(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Generic<T> {
+    Value(T),
+    Empty,
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_std_derives() {
+    let err = Generic::Value(1).try_into_empty().unwrap_err();
+    let copied = err;
+
+    assert_eq!(err, copied.clone());
+    assert_eq!(hash(&err), hash(&copied));
+    assert_eq!(err.cmp(&copied), Ordering::Equal);
+
+    let other = Generic::Value(2).try_into_empty().unwrap_err();
+    assert_ne!(err, other);
+    assert!(err < other);
+    assert!(Generic::<u32>::Empty.try_into_value().unwrap_err() > other);
+}
+
+#[test]
+fn test_bounded_on_enum() {
+    // the error is `Clone` because `Shared<NotClone>` is, even though `NotClone` is not
+    let err = Shared::Rc(Rc::new(NotClone)).try_into_empty().unwrap_err();
+    let cloned = err.clone();
+
+    assert_eq!(cloned.actual(), "Rc");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, EnumTryAsInner)]
+#[derive_err(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[try_as_inner(err_ignore_value)]
+enum Unordered {
+    Float(f64),
+    Empty,
+}
+
+#[test]
+fn test_ignore_value() {
+    let one = Unordered::Float(1.0).try_into_empty().unwrap_err();
+    let two = Unordered::Float(2.0).try_into_empty().unwrap_err();
+
+    assert_eq!(one, two);
+    assert_eq!(hash(&one), hash(&two));
+    assert_eq!(one.cmp(&two), Ordering::Equal);
+    assert_ne!(one, Unordered::Empty.try_into_float().unwrap_err());
+}
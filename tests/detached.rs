@@ -0,0 +1,87 @@
+//! Tests for detaching the generated error from the enum.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use std::error::Error;
+use std::rc::Rc;
+
+use enum_try_as_inner::{EnumTryAsInner, VariantMismatch};
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(detached)]
+enum Shared<'a> {
+    Rc(Rc<str>),
+    Borrowed(&'a str),
+}
+
+fn assert_static_send_sync<T: 'static + Send + Sync>(_: &T) {}
+
+fn borrowed(shared: Shared<'_>) -> std::result::Result<&str, SharedMismatchError> {
+    std::result::Result::Ok(shared.try_into_borrowed()?)
+}
+
+fn boxed(shared: Shared<'_>) -> std::result::Result<Rc<str>, Box<dyn Error + Send + Sync>> {
+    std::result::Result::Ok(shared.try_into_rc().map_err(SharedError::into_detached)?)
+}
+
+#[test]
+fn test_into_detached() {
+    let string = String::from("borrowed");
+    let err = Shared::Borrowed(&string).try_into_rc().unwrap_err();
+    let detached = err.into_detached();
+
+    assert_static_send_sync(&detached);
+    assert_eq!(detached.expected(), "Rc");
+    assert_eq!(detached.actual(), "Borrowed");
+    assert_eq!(
+        detached.to_string(),
+        "expected Shared::Rc, but got Shared::Borrowed"
+    );
+    assert_eq!(VariantMismatch::enum_name(&detached), "Shared");
+    assert!(!detached.has_value());
+}
+
+#[test]
+fn test_from() {
+    assert_eq!(borrowed(Shared::Borrowed("borrowed")).unwrap(), "borrowed");
+
+    let err = borrowed(Shared::Rc(Rc::from("rc"))).unwrap_err();
+    assert_eq!(err.actual(), "Rc");
+}
+
+#[test]
+fn test_boxed() {
+    assert_eq!(&*boxed(Shared::Rc(Rc::from("rc"))).unwrap(), "rc");
+
+    let err = boxed(Shared::Borrowed("borrowed")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected Shared::Rc, but got Shared::Borrowed"
+    );
+    assert!(err.downcast_ref::<SharedMismatchError>().is_some());
+}
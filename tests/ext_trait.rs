@@ -0,0 +1,112 @@
+//! Tests for generating the methods as an extension trait.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+mod exprs {
+    use enum_try_as_inner::EnumTryAsInner;
+
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(
+        trait = "ExprExt",
+        trait_vis = "pub(crate)",
+        markers,
+        split,
+        get,
+        or,
+        unchecked
+    )]
+    pub(crate) enum Expr<'a, T: Copy> {
+        #[try_as_inner(group = "literal")]
+        Int(T),
+        #[try_as_inner(group = "literal")]
+        Float(T),
+        Ident(&'a str),
+        /// A binary operation.
+        #[deprecated]
+        Binary(Box<Self>, Box<Self>),
+        Empty,
+    }
+
+    // no longer clashes with the generated `is_int`
+    impl<T: Copy> Expr<'_, T> {
+        pub(crate) fn is_int(&self) -> &'static str {
+            "inherent"
+        }
+
+        // nor with the helpers of the trait
+        pub(crate) fn variant_name(&self) -> String {
+            "inherent".into()
+        }
+    }
+
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(trait = "WrapperExt", infallible, pin)]
+    pub(crate) enum Wrapper<F> {
+        #[pin]
+        Value(F),
+    }
+}
+
+use exprs::{Expr, ExprExt, Wrapper, WrapperExt};
+
+#[test]
+fn test_trait() {
+    let int = Expr::Int(1_u8);
+
+    assert_eq!(int.is_int(), "inherent");
+    assert_eq!(int.variant_name(), "inherent");
+    assert!(ExprExt::is_int(&int));
+    assert!(<Expr<'_, u8> as ExprExt<'_, u8>>::is_literal(&int));
+    assert_eq!(*int.try_as_int().unwrap(), 1);
+    assert_eq!(int.try_get_int().unwrap(), 1);
+    assert_eq!(Expr::<u8>::Empty.int_or(2), 2);
+    assert_eq!(Expr::<u8>::Ident("x").try_into_ident().unwrap(), "x");
+    assert!(Expr::<u8>::Empty.is::<exprs::expr_variants::Empty>());
+    assert_eq!(Expr::Float(1_u8).try_as_literal().unwrap(), &1);
+
+    // SAFETY: the variant is known
+    assert_eq!(unsafe { Expr::Int(3_u8).into_int_unchecked() }, 3);
+
+    let err = Expr::<u8>::Empty.try_into_int().unwrap_err();
+    assert_eq!(err.actual(), "Empty");
+    assert_eq!(int.try_as_float().unwrap_err().actual(), "Int");
+    assert!(Expr::<u8>::Empty.split_empty().is_ok());
+}
+
+#[test]
+fn test_infallible_and_pin() {
+    let mut wrapper = Wrapper::Value(1_u32);
+
+    assert_eq!(*wrapper.as_value(), 1);
+    assert_eq!(
+        *std::pin::Pin::new(&mut wrapper).try_as_value_pin().unwrap(),
+        1
+    );
+    assert_eq!(wrapper.into_value(), 1);
+}
@@ -0,0 +1,137 @@
+//! Tests for the accessors of foreign enums.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+use std::borrow::Cow;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::IntErrorKind;
+use std::ops::Bound;
+
+mod foreign {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    enum_try_as_inner::foreign_enum! {
+        #[derive_err(Debug, Clone, PartialEq)]
+        #[try_as_inner(markers, get, err_vis = "pub")]
+        pub(crate) enum std::net::IpAddr {
+            V4(Ipv4Addr),
+            V6(Ipv6Addr),
+        }
+    }
+
+    enum_try_as_inner::foreign_enum! {
+        #[derive_err(Debug)]
+        #[try_as_inner(
+            trait = "BoundAccessors",
+            trait_vis = "pub(crate)",
+            or,
+            subset(Bounded = [Included, Excluded])
+        )]
+        pub(crate) enum core::ops::Bound<T> {
+            Included(T),
+            Excluded(T),
+            Unbounded,
+        }
+    }
+
+    enum_try_as_inner::foreign_enum! {
+        #[derive_err(Debug)]
+        pub(crate) enum std::borrow::Cow<'a, B>
+        where
+            B: 'a + ToOwned + ?Sized,
+        {
+            Borrowed(&'a B),
+            Owned(<B as ToOwned>::Owned),
+        }
+    }
+
+    enum_try_as_inner::foreign_enum! {
+        #[derive_err(Debug)]
+        #[non_exhaustive]
+        pub(crate) enum std::num::IntErrorKind {
+            Empty,
+            InvalidDigit,
+        }
+    }
+}
+
+use foreign::{BoundAccessors, CowExt, IntErrorKindExt, IpAddrExt};
+
+#[test]
+fn test_foreign() {
+    let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    assert!(ip.is_v4());
+    assert_eq!(ip.try_as_v4().unwrap(), &Ipv4Addr::LOCALHOST);
+    assert_eq!(ip.try_get_v4().unwrap(), Ipv4Addr::LOCALHOST);
+    assert!(ip.is::<foreign::ip_addr_variants::V4>());
+
+    let err: foreign::IpAddrError = ip.try_into_v6().unwrap_err();
+    assert_eq!(err.expected(), "V6");
+    assert_eq!(err.actual(), "V4");
+    assert_eq!(err.to_string(), "expected IpAddr::V6, but got IpAddr::V4");
+    assert_eq!(
+        err.into_value(),
+        std::option::Option::Some(IpAddr::V4(Ipv4Addr::LOCALHOST))
+    );
+    assert!(IpAddr::V6(Ipv6Addr::LOCALHOST).try_into_v6().is_ok());
+}
+
+#[test]
+fn test_generics() {
+    assert_eq!(Bound::Included(1).try_into_included().unwrap(), 1);
+    assert_eq!(Bound::<u8>::Unbounded.excluded_or(2), 2);
+    assert!(Bound::<u8>::Unbounded.is_unbounded());
+    assert!(matches!(
+        std::convert::TryFrom::try_from(Bound::Excluded(1)),
+        std::result::Result::Ok(foreign::Bounded::Excluded(1))
+    ));
+    assert_eq!(
+        Bound::from(foreign::Bounded::Included(1)),
+        Bound::Included(1)
+    );
+
+    let borrowed: Cow<'_, str> = Cow::Borrowed("str");
+    assert_eq!(borrowed.try_as_borrowed().unwrap(), &"str");
+    let owned: Cow<'_, str> = Cow::Owned("str".into());
+    assert_eq!(owned.try_into_owned().unwrap(), "str");
+}
+
+#[test]
+fn test_non_exhaustive() {
+    let err = "".parse::<u8>().unwrap_err();
+
+    assert!(err.kind().is_empty());
+    assert!(!IntErrorKind::InvalidDigit.is_empty());
+    assert_eq!(
+        IntErrorKind::PosOverflow
+            .try_as_empty()
+            .unwrap_err()
+            .actual(),
+        "<unknown>"
+    );
+}
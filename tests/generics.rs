@@ -52,3 +52,95 @@ fn with_generics() {
     assert!(with_generics.try_as_b().is_err());
     assert!(with_generics.try_as_b_mut().is_err());
 }
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, split, subset(Items = [Item]))]
+enum WithWhereClause<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    Iter(I),
+    Item(I::Item),
+}
+
+#[test]
+fn with_where_clause() {
+    let mut iter = WithWhereClause::Iter(vec![1, 2].into_iter());
+
+    assert_eq!(
+        iter.try_as_iter_mut().unwrap().next(),
+        std::option::Option::Some(1)
+    );
+    assert!(iter.is::<with_where_clause_variants::Iter>());
+
+    let err = iter.try_into_item().unwrap_err();
+    assert_eq!(err.actual(), "Iter");
+
+    let item = WithWhereClause::<std::vec::IntoIter<u32>>::Item(1);
+    assert!(matches!(item.split_iter(), std::result::Result::Err(_)));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(split)]
+enum WithConst<const N: usize> {
+    Full([u8; N]),
+    Partial(Vec<u8>),
+    Empty,
+}
+
+#[test]
+fn with_const_generics() {
+    let full = WithConst::Full([1, 2, 3]);
+
+    assert_eq!(full.try_as_full().unwrap(), &[1, 2, 3]);
+    assert_eq!(
+        WithConst::<3>::Empty.try_as_full().unwrap_err().actual(),
+        "Empty"
+    );
+    assert!(matches!(
+        WithConst::<3>::Empty.split_full(),
+        std::result::Result::Err(WithConstWithoutFull::Empty)
+    ));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers)]
+enum WithLifetimes<'a, 'b: 'a> {
+    Str(&'a str),
+    Bytes(&'b [u8]),
+}
+
+#[test]
+fn with_lifetimes() {
+    let string = String::from("str");
+    let borrowed = WithLifetimes::Str(&string);
+
+    assert_eq!(borrowed.try_as_str().unwrap(), &"str");
+    assert_eq!(borrowed.try_into_bytes().unwrap_err().actual(), "Str");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum WithDefault<T = u32, E: std::fmt::Debug = String> {
+    Value(T),
+    Error(E),
+}
+
+#[test]
+fn with_default_type_params() {
+    let value: WithDefault = WithDefault::Value(1);
+    let err: WithDefaultError = WithDefault::Error("error".into())
+        .try_into_value()
+        .unwrap_err();
+
+    assert_eq!(value.try_into_value().unwrap(), 1);
+    assert_eq!(err.actual(), "Error");
+}
@@ -0,0 +1,92 @@
+//! Tests for the variant group predicates and accessors.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(detached)]
+enum Value {
+    #[try_as_inner(group = "numeric", group = "scalar")]
+    Int(i64),
+    #[try_as_inner(group = "numeric", group = "scalar")]
+    Long {
+        value: i64,
+    },
+    #[try_as_inner(group = "scalar")]
+    Float(f64),
+    #[try_as_inner(group = "empty")]
+    Null,
+    #[try_as_inner(group = "empty")]
+    Undefined,
+    Str(String),
+}
+
+#[test]
+fn test_predicates() {
+    assert!(Value::Int(1).is_numeric());
+    assert!(Value::Long { value: 1 }.is_numeric());
+    assert!(!Value::Float(1.0).is_numeric());
+    assert!(Value::Float(1.0).is_scalar());
+    assert!(Value::Null.is_empty());
+    assert!(Value::Undefined.is_empty());
+    assert!(!Value::Str(String::new()).is_scalar());
+    assert!(!Value::Str(String::new()).is_empty());
+}
+
+#[test]
+fn test_accessors() {
+    let mut int = Value::Int(1);
+    let mut long = Value::Long { value: 2 };
+
+    assert_eq!(*int.try_as_numeric().unwrap(), 1);
+    assert_eq!(*long.try_as_numeric().unwrap(), 2);
+
+    *int.try_as_numeric_mut().unwrap() += 1;
+    *long.try_as_numeric_mut().unwrap() += 1;
+
+    assert_eq!(int.try_into_numeric().unwrap(), 2);
+    assert_eq!(long.try_into_numeric().unwrap(), 3);
+}
+
+#[test]
+fn test_errors() {
+    let err = Value::Float(1.0).try_into_numeric().unwrap_err();
+
+    assert_eq!(err.expected(), "Int | Long");
+    assert_eq!(err.actual(), "Float");
+    assert_eq!(
+        err.to_string(),
+        "expected Value::Int | Value::Long, but got Value::Float"
+    );
+    assert_eq!(
+        err.into_detached().to_string(),
+        "expected Value::Int | Value::Long, but got Value::Float"
+    );
+}
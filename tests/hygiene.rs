@@ -0,0 +1,205 @@
+//! Tests that the generated code does not depend on the names in scope, by shadowing the prelude
+//! and the macros used in the expansion for every option.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner as renamed;
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(
+        dead_code,
+        missing_copy_implementations,
+        missing_docs,
+        unused_imports,
+        unused_macros
+    )]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+
+    pub trait Clone {}
+    pub trait Copy {}
+    pub trait Debug {}
+    pub trait Default {}
+    pub trait Display {}
+    pub trait Drop {}
+    pub trait Eq {}
+    pub trait Error {}
+    pub trait FnOnce {}
+    pub trait From {}
+    pub trait Hash {}
+    pub trait Into {}
+    pub trait Ord {}
+    pub trait PartialEq {}
+    pub trait PartialOrd {}
+    pub trait Sized {}
+    pub trait TryFrom {}
+    pub trait Unpin {}
+
+    macro_rules! matches {
+        ($($tt:tt)*) => {
+            compile_error!("`matches!` must be qualified")
+        };
+    }
+    macro_rules! stringify {
+        ($($tt:tt)*) => {
+            compile_error!("`stringify!` must be qualified")
+        };
+    }
+    macro_rules! unreachable {
+        ($($tt:tt)*) => {
+            compile_error!("`unreachable!` must be qualified")
+        };
+    }
+    macro_rules! write {
+        ($($tt:tt)*) => {
+            compile_error!("`write!` must be qualified")
+        };
+    }
+    pub(crate) use {matches, stringify, unreachable, write};
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[try_as_inner(
+    markers,
+    split(derive(Debug)),
+    subset(Scalar = [Int, Value], derive(Debug)),
+    detached,
+    err_ignore_value,
+    get,
+    cloned,
+    or,
+    is_and,
+    unchecked,
+    pin,
+    inline = "always",
+    attr(must_use),
+    vis = "pub(crate)",
+    err_vis = "pub(crate)"
+)]
+enum Inherent<'a, T> {
+    #[pin]
+    #[try_as_inner(group = "scalar")]
+    Int(i64),
+    #[try_as_inner(group = "scalar", rename = "generic")]
+    Value(T),
+    #[try_as_inner(vis = "pub(in crate)")]
+    Str {
+        value: &'a str,
+    },
+    Empty,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(
+    trait = "ExtendedExt",
+    trait_vis = "pub(crate)",
+    crate = "renamed",
+    markers,
+    unit_is_only,
+    inline = "never"
+)]
+enum Extended<T> {
+    #[try_as_inner(group = "number", or, get)]
+    Int(i64),
+    #[try_as_inner(group = "number", split, cloned, unchecked)]
+    Float(f64),
+    Value(T),
+    Empty,
+}
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(infallible, inline = "none")]
+enum Single {
+    Only(u32, u64),
+}
+
+enum_try_as_inner::foreign_enum! {
+    #[derive_err(Debug, Clone, PartialEq)]
+    #[try_as_inner(markers, get, or, err_vis = "pub")]
+    enum core::ops::Bound<T> {
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+}
+
+#[test]
+fn test_inherent() {
+    let mut int = Inherent::<u8>::Int(1);
+
+    assert!(int.is_int());
+    assert!(int.is_scalar());
+    assert!(int.is_int_and(|value| *value == 1));
+    assert_eq!(int.try_get_int().unwrap(), 1);
+    assert_eq!(int.try_cloned_int().unwrap(), 1);
+    assert_eq!(int.clone().generic_or_default(), 0);
+    assert_eq!(*int.try_as_int().unwrap(), 1);
+    assert_eq!(
+        std::pin::Pin::new(&mut int)
+            .try_as_int_pin()
+            .map(|int| *int)
+            .unwrap(),
+        1
+    );
+    assert!(int.try_as::<inherent_variants::Str>().is_err());
+    // SAFETY: the variant is known
+    assert_eq!(unsafe { *int.as_int_unchecked() }, 1);
+
+    let err = Inherent::<u8>::Empty.try_into_str().unwrap_err();
+    assert_eq!(err.clone(), err);
+    assert_eq!(err.into_detached().actual(), "Empty");
+    assert!(std::convert::TryFrom::try_from(Inherent::<u8>::Value(2))
+        .map(|_: Scalar<u8>| ())
+        .is_ok());
+    assert!(Inherent::<u8>::Empty.split_int().is_err());
+}
+
+#[test]
+fn test_extended() {
+    let float = Extended::<u8>::Float(1.0);
+
+    assert!(float.is_number());
+    assert!(float.try_as_int().is_err());
+    assert_eq!(float.try_cloned_float().unwrap(), 1.0);
+    assert_eq!(Extended::<u8>::Empty.int_or(2), 2);
+    assert!(Extended::<u8>::Empty.is_empty());
+    assert!(Extended::<u8>::Empty
+        .try_as::<extended_variants::Empty>()
+        .is_ok());
+    assert!(float.split_float().is_ok());
+}
+
+#[test]
+fn test_single_and_foreign() {
+    assert_eq!(Single::Only(1, 2).into_only(), (1, 2));
+
+    let bound = core::ops::Bound::Excluded(1);
+    assert!(bound.is_excluded());
+    assert_eq!(bound.try_get_excluded().unwrap(), 1);
+    assert_eq!(bound.included_or(2), 2);
+    assert_eq!(bound.try_as_included().unwrap_err().actual(), "Excluded");
+}
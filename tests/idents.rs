@@ -0,0 +1,94 @@
+//! Tests for raw, keyword and non-ASCII variant names.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, split)]
+enum r#Token {
+    r#Type(String),
+    Größe(u32),
+    Σίγμα,
+}
+
+#[test]
+fn test_raw() {
+    assert!(Token::Type("u32".into()).is_type());
+    assert_eq!(Token::Type("u32".into()).try_into_type().unwrap(), "u32");
+    assert!(Token::Σίγμα.is::<token_variants::Σίγμα>());
+
+    let err: TokenError = Token::Σίγμα.try_into_type().unwrap_err();
+    assert_eq!(err.expected(), "Type");
+    assert_eq!(
+        err.to_string(),
+        "expected Token::Type, but got Token::Σίγμα"
+    );
+}
+
+#[test]
+fn test_non_ascii() {
+    assert_eq!(*Token::Größe(1).try_as_größe().unwrap(), 1);
+    assert!(Token::Σίγμα.is_σίγμα());
+    assert!(Token::Größe(1).split_type().is_err());
+}
+
+mod keywords {
+    #![allow(dead_code, non_camel_case_types)]
+
+    use enum_try_as_inner::EnumTryAsInner;
+
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(markers, split)]
+    pub(crate) enum r#enum {
+        r#fn { name: String },
+        r#match,
+    }
+}
+
+#[test]
+fn test_keywords() {
+    use keywords::r#enum;
+
+    assert_eq!(
+        r#enum::r#fn {
+            name: "main".into()
+        }
+        .try_into_fn()
+        .unwrap(),
+        "main"
+    );
+    assert!(r#enum::r#match.is_match());
+    assert!(r#enum::r#match.is::<keywords::enum_variants::r#match>());
+
+    let err: keywords::enumError = r#enum::r#match.try_into_fn().unwrap_err();
+    assert_eq!(err.actual(), "match");
+    assert_eq!(err.to_string(), "expected enum::fn, but got enum::match");
+}
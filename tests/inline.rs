@@ -0,0 +1,113 @@
+//! Tests for the inline attribute of the generated methods.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(inline = "always", markers, or, unit_is_only)]
+enum Always {
+    #[try_as_inner(get)]
+    Int(i64),
+    Str(String),
+    Empty,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(inline = "never", split, infallible)]
+enum Never {
+    Int(i64),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(inline = "none")]
+enum NoHint<T> {
+    #[try_as_inner(group = "number")]
+    Value(T),
+    #[try_as_inner(group = "number")]
+    Other(T),
+    Empty(),
+}
+
+#[test]
+fn test_inline() {
+    assert_eq!(*Always::Int(1).try_as_int().unwrap(), 1);
+    assert_eq!(Always::Empty.try_get_int().unwrap_err().actual(), "Empty");
+    assert!(Always::Empty.try_as::<always_variants::Empty>().is_ok());
+    assert_eq!(Never::Int(1).into_int(), 1);
+    assert_eq!(NoHint::Other(1).try_into_number().unwrap(), 1);
+}
+
+#[test]
+fn test_mismatch() {
+    // the shared mismatch path still keeps the value of the enum
+    let err = Always::Str("str".into()).try_into_int().unwrap_err();
+    assert_eq!(err.expected(), "Int");
+    assert_eq!(err.actual(), "Str");
+    assert!(matches!(
+        err.into_value(),
+        std::option::Option::Some(Always::Str(_))
+    ));
+
+    let err = NoHint::<u8>::Empty().try_as_number_mut().unwrap_err();
+    assert_eq!(err.expected(), "Value | Other");
+    assert_eq!(err.actual(), "Empty");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum OwnHelpers {
+    Int(i64),
+    Str(String),
+}
+
+impl OwnHelpers {
+    fn variant_mismatch(&self, expected: &str) -> String {
+        format!("not {}", expected)
+    }
+
+    fn into_variant_mismatch(self, expected: &str) -> String {
+        self.variant_mismatch(expected)
+    }
+}
+
+#[test]
+fn test_own_helpers() {
+    // the shared mismatch paths do not take the names of the methods of the enum
+    assert_eq!(OwnHelpers::Int(1).into_variant_mismatch("Str"), "not Str");
+    assert_eq!(
+        OwnHelpers::Int(1).try_into_str().unwrap_err().actual(),
+        "Int"
+    );
+}
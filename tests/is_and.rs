@@ -0,0 +1,100 @@
+//! Tests for the predicates checking the fields of a variant.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(is_and)]
+enum Shape<F> {
+    Circle(f64),
+    Rect(f64, f64),
+    Named { name: String, sides: u32 },
+    Custom(F),
+    Empty,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Setting {
+    #[try_as_inner(is_and)]
+    Int(i64),
+    Str(String),
+}
+
+// without the option, the predicate of `FooAnd` does not collide with `is_foo_and`
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Regress {
+    Foo(u8),
+    FooAnd(u8),
+}
+
+#[test]
+fn test_is_and() {
+    let circle = Shape::<()>::Circle(2.0);
+
+    assert!(circle.is_circle_and(|radius| *radius > 1.0));
+    assert!(!circle.is_circle_and(|radius| *radius > 3.0));
+    assert!(!circle.is_rect_and(|_| true));
+    assert!(Shape::<()>::Rect(1.0, 2.0).is_rect_and(|(w, h)| w < h));
+    assert!(Shape::<()>::Named {
+        name: "triangle".into(),
+        sides: 3
+    }
+    .is_named_and(|(name, sides)| name.len() > 3 && *sides == 3));
+    assert!(!Shape::<()>::Empty.is_circle_and(|_| true));
+}
+
+#[test]
+fn test_is_and_then() {
+    let named = Shape::<()>::Named {
+        name: "square".into(),
+        sides: 4,
+    };
+
+    assert!(named.is_named_and_then(|(name, _)| name == "square"));
+    assert!(Shape::Custom(vec![1]).is_custom_and_then(|custom| custom.len() == 1));
+    assert!(!Shape::<()>::Circle(1.0).is_custom_and_then(|_| true));
+}
+
+#[test]
+fn test_variant_option() {
+    assert!(Setting::Int(1).is_int_and(|int| *int == 1));
+    assert!(!Setting::Str("str".into()).is_int_and_then(|_| true));
+}
+
+#[test]
+fn test_opt_in() {
+    assert!(Regress::FooAnd(1).is_foo_and());
+    assert!(!Regress::Foo(1).is_foo_and());
+    assert!(Regress::Foo(1).is_foo());
+}
@@ -0,0 +1,197 @@
+//! Tests for the variant marker types and type-directed access.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use std::convert::{TryFrom, TryInto};
+
+use enum_try_as_inner::{EnumTryAsInner, TryAsVariant};
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers)]
+enum ManyVariants {
+    Zero,
+    One(u32),
+    Two(u32, i32),
+    Named { one: bool },
+}
+
+use many_variants_variants::{Named, One, Two, Zero};
+
+#[test]
+fn test_inherent() {
+    let mut many = ManyVariants::Two(1, 2);
+
+    assert!(many.is::<Two>());
+    assert!(!many.is::<One>());
+    assert_eq!(many.try_as::<Two>().unwrap(), (&1, &2));
+    assert_eq!(many.try_as_mut::<Two>().unwrap(), (&mut 1, &mut 2));
+    assert!(many.try_as::<Named>().is_err());
+
+    let err = many.try_into::<Zero>().unwrap_err();
+    assert_eq!(err.expected(), "Zero");
+    assert_eq!(
+        err.into_value(),
+        std::option::Option::Some(ManyVariants::Two(1, 2))
+    );
+}
+
+#[test]
+fn test_unit() {
+    let mut many = ManyVariants::Zero;
+
+    assert!(many.is::<Zero>());
+    assert_eq!(many.try_as::<Zero>().unwrap(), ());
+    assert!(many.try_as_mut::<Zero>().is_ok());
+    assert!(many.try_as_mut::<One>().is_err());
+    assert!(many.try_into::<Zero>().is_ok());
+}
+
+impl TryFrom<ManyVariants> for u32 {
+    type Error = ManyVariants;
+
+    fn try_from(value: ManyVariants) -> std::result::Result<Self, Self::Error> {
+        match value {
+            ManyVariants::One(one) => std::result::Result::Ok(one),
+            other => std::result::Result::Err(other),
+        }
+    }
+}
+
+#[test]
+fn test_try_from() {
+    // the inherent `try_into::<V>()` shadows `TryInto::try_into`, so other conversions have to be
+    // called through `TryFrom` or with the trait path
+    assert_eq!(
+        u32::try_from(ManyVariants::One(1)),
+        std::result::Result::Ok(1)
+    );
+
+    let one: std::result::Result<u32, _> = TryInto::try_into(ManyVariants::One(2));
+    assert_eq!(one, std::result::Result::Ok(2));
+    assert!(u32::try_from(ManyVariants::Zero).is_err());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, subset(Literal = [Int, Str], derive(Debug, PartialEq)))]
+enum WithSubset {
+    Int(i64),
+    Str(String),
+    List(Vec<Self>),
+}
+
+#[test]
+fn test_subset() {
+    let literal = Literal::try_from(WithSubset::Int(1)).unwrap();
+    assert_eq!(literal, Literal::Int(1));
+
+    let literal: std::result::Result<Literal, _> = TryInto::try_into(WithSubset::Str("str".into()));
+    assert_eq!(literal.unwrap(), Literal::Str("str".into()));
+
+    assert_eq!(
+        WithSubset::Int(1)
+            .try_into::<with_subset_variants::Int>()
+            .unwrap(),
+        1
+    );
+}
+
+fn take_all<V, E: TryAsVariant<V>>(values: Vec<E>) -> Vec<E::Inner> {
+    values
+        .into_iter()
+        .filter_map(|value| value.try_into_variant().ok())
+        .collect()
+}
+
+fn increment<V, E>(value: &mut E)
+where
+    E: for<'a> TryAsVariant<V, Mut<'a> = &'a mut u32>,
+{
+    if let std::result::Result::Ok(inner) = value.try_as_variant_mut() {
+        *inner += 1;
+    }
+}
+
+#[test]
+fn test_generic() {
+    let values = vec![
+        ManyVariants::One(1),
+        ManyVariants::Named { one: true },
+        ManyVariants::One(2),
+        ManyVariants::Zero,
+    ];
+
+    assert_eq!(take_all::<One, _>(values), vec![1, 2]);
+
+    let mut one = ManyVariants::One(1);
+    increment::<One, _>(&mut one);
+    assert_eq!(one, ManyVariants::One(2));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(markers)]
+enum WithGenerics<'a, T> {
+    A(T),
+    B(&'a str),
+}
+
+#[test]
+fn test_generics() {
+    let with_generics = WithGenerics::<u32>::B("b");
+
+    assert!(with_generics.is::<with_generics_variants::B>());
+    assert_eq!(
+        *with_generics
+            .try_as::<with_generics_variants::B>()
+            .unwrap_or(&""),
+        "b"
+    );
+    assert!(with_generics.try_as::<with_generics_variants::A>().is_err());
+}
+
+mod renamed {
+    use enum_try_as_inner as renamed_crate;
+    use enum_try_as_inner::EnumTryAsInner;
+
+    #[allow(dead_code)]
+    #[derive(Debug, EnumTryAsInner)]
+    #[try_as_inner(markers, crate = "renamed_crate")]
+    pub(crate) enum Renamed {
+        A(u32),
+        B,
+    }
+}
+
+#[test]
+fn test_crate_path() {
+    let renamed = renamed::Renamed::A(1);
+
+    assert!(renamed.is::<renamed::renamed_variants::A>());
+}
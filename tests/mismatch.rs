@@ -0,0 +1,109 @@
+//! Tests for the `VariantMismatch` trait implemented by the generated errors.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::{EnumTryAsInner, VariantMismatch};
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+impl Shape {
+    /// A hand-written accessor producing the same error as the generated ones.
+    fn try_radius(&self) -> std::result::Result<f64, ShapeError> {
+        match self {
+            Self::Circle(radius) => std::result::Result::Ok(*radius),
+            Self::Square(_) => std::result::Result::Err(ShapeError::new(
+                "Circle",
+                "Square",
+                std::option::Option::None,
+            )),
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+enum Borrowed<'a> {
+    Str(&'a str),
+    Int(i64),
+}
+
+fn describe(err: &dyn VariantMismatch) -> String {
+    format!(
+        "{}: expected {}, got {}, has value: {}",
+        err.enum_name(),
+        err.expected(),
+        err.actual(),
+        err.has_value()
+    )
+}
+
+#[test]
+fn test_uniform() {
+    let shape_err = Shape::Square(1.0).try_as_circle().unwrap_err();
+    let string = String::from("borrowed");
+    let borrowed_err = Borrowed::Str(&string).try_into_int().unwrap_err();
+
+    let errors: Vec<&dyn VariantMismatch> = vec![&shape_err, &borrowed_err];
+    let descriptions = errors.into_iter().map(describe).collect::<Vec<_>>();
+
+    assert_eq!(
+        descriptions,
+        vec![
+            "Shape: expected Circle, got Square, has value: false",
+            "Borrowed: expected Int, got Str, has value: true",
+        ]
+    );
+}
+
+#[test]
+fn test_value_any() {
+    let err: Box<dyn VariantMismatch> = Box::new(Shape::Square(2.0).try_into_circle().unwrap_err());
+
+    assert_eq!(
+        err.value_any().unwrap().downcast_ref::<Shape>(),
+        std::option::Option::Some(&Shape::Square(2.0))
+    );
+
+    let err = Shape::Square(2.0).try_as_circle().unwrap_err();
+    assert!(err.value_any().is_none());
+}
+
+#[test]
+fn test_constructor() {
+    assert_eq!(Shape::Circle(1.0).try_radius().unwrap(), 1.0);
+
+    let err = Shape::Square(1.0).try_radius().unwrap_err();
+    assert_eq!(
+        describe(&err),
+        "Shape: expected Circle, got Square, has value: false"
+    );
+}
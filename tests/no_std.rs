@@ -0,0 +1,41 @@
+//! Tests that the generated code does not depend on `std`.
+
+#![no_std]
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+#[allow(dead_code)]
+#[derive(Clone, PartialEq, EnumTryAsInner)]
+#[derive_err(Clone, PartialEq)]
+#[try_as_inner(detached, markers)]
+enum NoStd {
+    One(u32),
+    Two { two: bool },
+    Three,
+}
+
+fn assert_error<E: core::error::Error>(_: &E) {}
+
+#[test]
+fn test_no_std() {
+    let err = NoStd::Three.try_into_one().unwrap_err();
+
+    assert_eq!(err.expected(), "One");
+    let detached = err.into_detached();
+    assert_error(&detached);
+    assert_eq!(detached.actual(), "Three");
+    assert!(NoStd::Two { two: true }.is_two());
+}
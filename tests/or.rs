@@ -0,0 +1,92 @@
+//! Tests for the helpers falling back to a default if the variant does not match.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(or)]
+enum Setting {
+    Str(String),
+    Int(i64),
+    Range(u32, u32),
+    Path { path: std::path::PathBuf },
+    Unset,
+}
+
+#[test]
+fn test_or() {
+    assert_eq!(Setting::Str("str".into()).str_or("default".into()), "str");
+    assert_eq!(Setting::Unset.str_or("default".into()), "default");
+    assert_eq!(Setting::Int(1).range_or((0, 10)), (0, 10));
+    assert_eq!(Setting::Range(1, 2).range_or((0, 10)), (1, 2));
+}
+
+#[test]
+fn test_or_else() {
+    assert_eq!(Setting::Int(1).int_or_else(|| 2), 1);
+    assert_eq!(Setting::Unset.int_or_else(|| 2), 2);
+}
+
+#[test]
+fn test_or_default() {
+    assert_eq!(Setting::Unset.str_or_default(), "");
+    assert_eq!(Setting::Int(3).int_or_default(), 3);
+    assert_eq!(Setting::Unset.range_or_default(), (0, 0));
+    assert!(Setting::Unset.path_or_default().as_os_str().is_empty());
+}
+
+#[test]
+fn test_ref_or() {
+    let default = String::from("default");
+    let str = Setting::Str("str".into());
+
+    assert_eq!(str.str_ref_or(&default), "str");
+    assert_eq!(Setting::Unset.str_ref_or(&default), "default");
+    assert_eq!(Setting::Unset.range_ref_or((&1, &2)), (&1, &2));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Shape {
+    #[try_as_inner(or)]
+    Circle(f64),
+    // not `Default`, which only matters when calling `*_or_default`
+    #[try_as_inner(or)]
+    Custom(std::num::NonZeroU32),
+}
+
+#[test]
+fn test_variant_option() {
+    let one = std::num::NonZeroU32::new(1).unwrap();
+
+    assert_eq!(Shape::Custom(one).circle_or_default(), 0.0);
+    assert_eq!(Shape::Circle(1.0).custom_or(one), one);
+}
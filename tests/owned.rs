@@ -0,0 +1,89 @@
+//! Tests for the accessors copying or cloning the fields out of a variant.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(get)]
+enum Token<'a> {
+    Int(i64),
+    Id(&'a str),
+    Span(usize, usize),
+    Point { x: f32, y: f32 },
+    Eof,
+}
+
+#[test]
+fn test_get() {
+    assert_eq!(Token::Int(1).try_get_int().unwrap(), 1);
+    assert_eq!(Token::Id("id").try_get_id().unwrap(), "id");
+    assert_eq!(Token::Span(1, 2).try_get_span().unwrap(), (1, 2));
+    assert_eq!(
+        Token::Point { x: 1.0, y: 2.0 }.try_get_point().unwrap(),
+        (1.0, 2.0)
+    );
+
+    let err = Token::Eof.try_get_int().unwrap_err();
+    assert_eq!(err.expected(), "Int");
+    assert_eq!(err.actual(), "Eof");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Value<T> {
+    #[try_as_inner(get, cloned)]
+    Int(i64),
+    #[try_as_inner(cloned)]
+    Str(String),
+    #[try_as_inner(get, cloned)]
+    Pair(T, u32),
+}
+
+#[test]
+fn test_cloned() {
+    let value = Value::<u8>::Str("str".into());
+
+    assert_eq!(value.try_cloned_str().unwrap(), "str");
+    assert_eq!(value.try_as_str().unwrap(), "str");
+    assert_eq!(Value::<u8>::Int(1).try_cloned_int().unwrap(), 1);
+    assert_eq!(
+        Value::Pair(vec![1], 2).try_cloned_pair().unwrap(),
+        (vec![1], 2)
+    );
+    assert!(Value::<u8>::Int(1).try_cloned_str().is_err());
+}
+
+#[test]
+fn test_generic_bounds() {
+    // the copying accessor of generic payloads only exists if they are `Copy`
+    assert_eq!(Value::Pair(1_u8, 2).try_get_pair().unwrap(), (1, 2));
+    assert_eq!(Value::<u8>::Int(1).try_get_int().unwrap(), 1);
+}
@@ -0,0 +1,132 @@
+//! Tests for the pin projecting accessors.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, Default)]
+struct Tracker {
+    polled: u32,
+    _pinned: PhantomPinned,
+}
+
+impl Tracker {
+    fn poll(self: Pin<&mut Self>) -> u32 {
+        // SAFETY: `polled` is not structurally pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        this.polled += 1;
+        this.polled
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum State<F> {
+    #[pin]
+    Waiting(F),
+    #[pin]
+    Joined {
+        left: F,
+        right: F,
+    },
+    Done(u32),
+    Idle,
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+#[test]
+fn test_pinned_variant() {
+    let mut state = Box::pin(State::Waiting(Tracker::default()));
+
+    assert_eq!(state.as_mut().try_as_waiting_pin().unwrap().poll(), 1);
+    assert_eq!(state.as_mut().try_as_waiting_pin().unwrap().poll(), 2);
+
+    assert!(state.as_mut().try_as_joined_pin().is_err());
+    assert!(state.as_mut().try_as_done_pin().is_err());
+    assert_eq!(state.try_as_waiting().unwrap().polled, 2);
+}
+
+#[test]
+fn test_pinned_named_variant() {
+    let mut state = Box::pin(State::Joined {
+        left: Tracker::default(),
+        right: Tracker::default(),
+    });
+
+    let (left, right) = state.as_mut().try_as_joined_pin().unwrap();
+    assert_eq!(left.poll(), 1);
+    assert_eq!(right.poll(), 1);
+
+    let err = state.as_mut().try_as_waiting_pin().unwrap_err();
+    assert_eq!(err.expected(), "Waiting");
+    assert_eq!(err.actual(), "Joined");
+}
+
+#[test]
+fn test_unpinned_variant() {
+    let mut state = Box::pin(State::<Tracker>::Done(1));
+
+    let done: &mut u32 = state.as_mut().try_as_done_pin().unwrap();
+    *done += 1;
+
+    assert_eq!(*state.try_as_done().unwrap(), 2);
+    assert!(state.as_mut().try_as_waiting_pin().is_err());
+}
+
+#[test]
+fn test_unpin() {
+    assert_unpin::<State<u32>>();
+
+    // only the pinned fields determine if the enum is `Unpin`
+    assert_unpin::<Unpinned>();
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(pin)]
+enum Unpinned {
+    Tracker(Tracker),
+    Other(u32, u32),
+}
+
+#[test]
+fn test_pin_without_pinned_variants() {
+    let mut unpinned = Unpinned::Other(1, 2);
+    let mut pinned = Pin::new(&mut unpinned);
+
+    let (first, second) = pinned.as_mut().try_as_other_pin().unwrap();
+    std::mem::swap(first, second);
+
+    assert!(pinned.as_mut().try_as_tracker_pin().is_err());
+    assert_eq!(unpinned.try_into_other().unwrap(), (2, 1));
+}
@@ -0,0 +1,76 @@
+//! Tests for renaming the generated methods of a variant.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+mod names {
+    #![allow(dead_code, non_camel_case_types)]
+
+    use enum_try_as_inner::EnumTryAsInner;
+
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(split, unit_is_only)]
+    pub(crate) enum Name {
+        FooBar(u32),
+        #[try_as_inner(rename = "foo_bar_legacy")]
+        Foo_Bar(u32),
+        #[try_as_inner(group = "number")]
+        Int(i64),
+        #[try_as_inner(rename = "number_value")]
+        Number,
+        // with `unit_is_only` unit variants have no `try_as_*_mut`, so this does not collide with `Number`
+        #[try_as_inner(rename = "number_value_mut")]
+        NumberMut(u32),
+    }
+}
+
+use names::Name;
+
+#[test]
+fn test_rename() {
+    assert!(Name::Foo_Bar(1).is_foo_bar_legacy());
+    assert!(!Name::Foo_Bar(1).is_foo_bar());
+    assert_eq!(Name::FooBar(1).try_into_foo_bar().unwrap(), 1);
+    assert_eq!(*Name::Foo_Bar(2).try_as_foo_bar_legacy().unwrap(), 2);
+    assert!(matches!(
+        Name::Foo_Bar(3).split_foo_bar_legacy(),
+        std::result::Result::Ok(3)
+    ));
+
+    assert!(Name::Int(1).is_number());
+    assert!(Name::Number.is_number_value());
+    assert_eq!(*Name::NumberMut(1).try_as_number_value_mut().unwrap(), 1);
+}
+
+#[test]
+fn test_err() {
+    // the error still refers to the variant by its name
+    let err = Name::Number.try_into_foo_bar_legacy().unwrap_err();
+
+    assert_eq!(err.expected(), "Foo_Bar");
+    assert_eq!(err.actual(), "Number");
+}
@@ -0,0 +1,108 @@
+//! Tests for the `split_*` functions and their remainders.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[try_as_inner(split(derive(Debug, PartialEq)))]
+enum Token {
+    Number(u32),
+    Ident { name: String },
+    Comma,
+}
+
+#[test]
+fn test_split() {
+    assert_eq!(Token::Number(1).split_number().unwrap(), 1);
+
+    let remainder = Token::Comma.split_number().unwrap_err();
+    // the remainder can be matched without handling `Number`
+    match remainder {
+        TokenWithoutNumber::Ident { .. } => panic!("expected a comma"),
+        TokenWithoutNumber::Comma => {}
+    }
+
+    assert_eq!(Token::Comma.split_comma().unwrap(), ());
+    assert_eq!(
+        Token::Number(1).split_ident().unwrap_err(),
+        TokenWithoutIdent::Number(1)
+    );
+}
+
+#[test]
+fn test_from_remainder() {
+    let remainder = Token::Ident {
+        name: "name".into(),
+    }
+    .split_comma()
+    .unwrap_err();
+
+    assert_eq!(
+        Token::from(remainder),
+        Token::Ident {
+            name: "name".into()
+        }
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+enum Partial<T> {
+    #[try_as_inner(split(derive(Debug)))]
+    Value(T),
+    Missing,
+    Error(String),
+}
+
+#[test]
+fn test_variant_split() {
+    let remainder = Partial::<u32>::Missing.split_value().unwrap_err();
+
+    // the remainder does not depend on `T`
+    let remainder: PartialWithoutValue = remainder;
+    assert!(matches!(remainder, PartialWithoutValue::Missing));
+
+    assert_eq!(Partial::Value(1).split_value().unwrap(), 1);
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(split)]
+enum Single {
+    Only(u32),
+}
+
+#[test]
+fn test_single() {
+    let value: std::result::Result<u32, SingleWithoutOnly> = Single::Only(1).split_only();
+
+    match value {
+        std::result::Result::Ok(value) => assert_eq!(value, 1),
+        std::result::Result::Err(remainder) => match remainder {},
+    }
+}
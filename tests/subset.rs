@@ -0,0 +1,105 @@
+//! Tests for the generated subset enums.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use std::convert::TryFrom;
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(subset(Literal = [Int, Float, Str], derive(Debug, PartialEq)))]
+#[try_as_inner(subset(Empty = [Null], Nested = [List]))]
+enum Value {
+    Int(i64),
+    Float(f64),
+    Str { value: String },
+    Null,
+    List(Vec<Self>),
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Literal::try_from(Value::Int(1)).unwrap(), Literal::Int(1));
+    assert_eq!(
+        Literal::try_from(Value::Str {
+            value: "str".into()
+        })
+        .unwrap(),
+        Literal::Str {
+            value: "str".into()
+        }
+    );
+    assert!(matches!(Empty::try_from(Value::Null).unwrap(), Empty::Null));
+}
+
+#[test]
+fn test_try_from_err() {
+    let err = Literal::try_from(Value::List(vec![])).unwrap_err();
+
+    assert_eq!(err.expected(), "Int | Float | Str");
+    assert_eq!(err.actual(), "List");
+    assert_eq!(
+        err.into_value(),
+        std::option::Option::Some(Value::List(vec![]))
+    );
+}
+
+#[test]
+fn test_from() {
+    assert_eq!(Value::from(Literal::Float(1.0)), Value::Float(1.0));
+    assert_eq!(Value::from(Empty::Null), Value::Null);
+}
+
+#[test]
+fn test_self_fields() {
+    // `Self` in the fields of a subset still refers to the original enum
+    let nested = Nested::List(vec![Value::Null]);
+
+    assert_eq!(Value::from(nested), Value::List(vec![Value::Null]));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(subset(Borrowed = [Str, Pair], Owned = [Value]))]
+enum WithGenerics<'a, T: Clone, U> {
+    Str(&'a str),
+    Pair(&'a str, U),
+    Value(T),
+}
+
+#[test]
+fn test_generics() {
+    let borrowed = Borrowed::<u32>::try_from(WithGenerics::<(), u32>::Pair("pair", 1)).unwrap();
+    assert!(matches!(borrowed, Borrowed::Pair("pair", 1)));
+
+    let owned: Owned<u32> = Owned::try_from(WithGenerics::<_, ()>::Value(1_u32)).unwrap();
+    let value = WithGenerics::<_, ()>::from(owned);
+    assert_eq!(value.try_into_value().unwrap(), 1);
+}
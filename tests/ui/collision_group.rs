@@ -0,0 +1,10 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+enum Value {
+    #[try_as_inner(group = "numeric")]
+    Int(i64),
+    Numeric(f64),
+}
+
+fn main() {}
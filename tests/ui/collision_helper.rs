@@ -0,0 +1,11 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(markers)]
+enum Value {
+    Int(i64),
+    #[try_as_inner(rename = "mut")]
+    Mutable(i64),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(is_and)]
+enum Regress {
+    Foo(u8),
+    FooAnd(u8),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, EnumTryAsInner)]
+enum Value {
+    FooBar(u32),
+    Foo_Bar(u32),
+}
+
+fn main() {}
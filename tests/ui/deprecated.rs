@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Value {
+    Current(u32),
+    #[deprecated(note = "use `Value::Current` instead")]
+    Legacy(u32),
+}
+
+fn main() {
+    let value = Value::Current(1);
+    let _ = value.is_legacy();
+    let _ = value.try_as_legacy();
+}
@@ -0,0 +1,9 @@
+enum_try_as_inner::foreign_enum! {
+    #[derive_err(Debug)]
+    enum core::ops::Bound<T> {
+        Included(T),
+        Excluded(T),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::net::Ipv4Addr;
+
+enum_try_as_inner::foreign_enum! {
+    #[derive_err(Debug)]
+    enum std::net::IpAddr {
+        V4(Ipv4Addr),
+        V6(Ipv4Addr),
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+enum_try_as_inner::foreign_enum! {
+    #[derive_err(Debug)]
+    enum core::ops::Bound<T> {
+        Included(T),
+        Excluded { value: T },
+        Unbounded,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+enum Value {
+    #[try_as_inner(group = "½")]
+    Int(i64),
+    Str(String),
+}
+
+fn main() {}
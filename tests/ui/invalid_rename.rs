@@ -0,0 +1,10 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+enum Value {
+    Int(i64),
+    #[try_as_inner(rename = "½")]
+    Str(String),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::convert::TryInto;
+
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, subset(Literal = [Int], derive(Debug)))]
+enum Value {
+    Int(i64),
+    List(Vec<Value>),
+}
+
+fn main() {
+    let literal: Result<Literal, _> = Value::Int(1).try_into();
+    let _ = literal;
+}
@@ -0,0 +1,15 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(EnumTryAsInner)]
+enum State {
+    #[pin]
+    Waiting(u32),
+    Done(u32),
+}
+
+// moving out of the pinned fields in `drop` would be unsound
+impl Drop for State {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use std::marker::PhantomPinned;
+
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(EnumTryAsInner)]
+enum State<F> {
+    #[pin]
+    Waiting(F),
+    Done(u32),
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    // a pinned `!Unpin` payload makes the enum `!Unpin`
+    assert_unpin::<State<PhantomPinned>>();
+}
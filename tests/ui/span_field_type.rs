@@ -0,0 +1,12 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(get)]
+enum Value {
+    Int(i64),
+    Str(String),
+}
+
+fn main() {
+    let _ = Value::Str(String::new()).try_get_str();
+}
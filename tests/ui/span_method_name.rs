@@ -0,0 +1,11 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+enum Value {
+    Int(i64),
+    Str(String),
+}
+
+fn main() {
+    let _ = Value::Int(1).is_str(true);
+}
@@ -0,0 +1,11 @@
+use enum_try_as_inner::EnumTryAsInner;
+
+#[derive(Debug, EnumTryAsInner)]
+#[try_as_inner(trait = "ValueExt")]
+pub enum Value {
+    Int(i64),
+    #[try_as_inner(vis = "pub(crate)")]
+    Str(String),
+}
+
+fn main() {}
@@ -0,0 +1,76 @@
+//! Tests for the unsafe accessors which do not check the variant.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(unchecked)]
+enum Op {
+    Push(i64),
+    Jump {
+        target: usize,
+        cond: bool,
+    },
+    #[try_as_inner(rename = "call_function")]
+    Call(String),
+    Halt,
+}
+
+#[test]
+fn test_unchecked() {
+    let mut push = Op::Push(1);
+
+    // SAFETY: the variants are known
+    unsafe {
+        assert_eq!(*push.as_push_unchecked(), 1);
+        *push.as_push_unchecked_mut() += 1;
+        assert_eq!(push.into_push_unchecked(), 2);
+
+        let jump = Op::Jump {
+            target: 3,
+            cond: true,
+        };
+        assert_eq!(jump.as_jump_unchecked(), (&3, &true));
+        assert_eq!(
+            Op::Call("main".into()).into_call_function_unchecked(),
+            "main"
+        );
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "expected Op::Push, but got Op::Halt")]
+fn test_debug_assert() {
+    // SAFETY: not sound, but the mismatch panics in debug builds before reaching undefined behavior
+    unsafe {
+        Op::Halt.into_push_unchecked();
+    }
+}
@@ -59,3 +59,73 @@ fn test_two_unit() {
     assert!(!unit.is_one());
     assert!(unit.is_two());
 }
+
+#[derive(Debug, PartialEq, EnumTryAsInner)]
+#[derive_err(Debug)]
+enum Payloadless {
+    Unit,
+    Tuple(),
+    Struct {},
+    Value(u32),
+}
+
+#[test]
+fn test_payloadless_accessors() {
+    let mut unit = Payloadless::Unit;
+    let mut tuple = Payloadless::Tuple();
+    let mut strukt = Payloadless::Struct {};
+
+    // all payload-less variants return `()` from every accessor
+    let () = unit.try_as_unit().unwrap();
+    let () = unit.try_as_unit_mut().unwrap();
+    let () = tuple.try_as_tuple().unwrap();
+    let () = tuple.try_as_tuple_mut().unwrap();
+    let () = strukt.try_as_struct().unwrap();
+    let () = strukt.try_as_struct_mut().unwrap();
+
+    assert_eq!(unit.try_into_unit().unwrap(), ());
+    assert_eq!(tuple.try_into_tuple().unwrap(), ());
+    assert_eq!(strukt.try_into_struct().unwrap(), ());
+}
+
+#[test]
+fn test_payloadless_errors() {
+    let mut value = Payloadless::Value(1);
+
+    assert_eq!(value.try_as_unit().unwrap_err().actual(), "Value");
+    assert_eq!(value.try_as_tuple_mut().unwrap_err().expected(), "Tuple");
+
+    let err = value.try_into_struct().unwrap_err();
+    assert_eq!(err.expected(), "Struct");
+    assert_eq!(
+        err.into_value(),
+        std::option::Option::Some(Payloadless::Value(1))
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, unit_is_only)]
+enum Flag {
+    On,
+    Off(),
+    Level(u8),
+}
+
+#[test]
+fn test_unit_is_only() {
+    let mut flag = Flag::On;
+
+    assert!(flag.is_on());
+    assert!(!flag.is_off());
+    assert_eq!(*Flag::Level(1).try_as_level().unwrap(), 1);
+
+    // the markers still work without the accessors
+    flag.try_as_mut::<flag_variants::On>().unwrap();
+    assert_eq!(
+        flag.try_as::<flag_variants::Off>().unwrap_err().actual(),
+        "On"
+    );
+    assert!(flag.try_into::<flag_variants::On>().is_ok());
+}
@@ -0,0 +1,76 @@
+//! Tests for enums without variants and enums with a single variant.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+use enum_try_as_inner::EnumTryAsInner;
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(markers, split)]
+enum Never {}
+
+#[test]
+fn test_uninhabited() {
+    let never = std::option::Option::None::<Never>;
+    let err = NeverError::new("Expected", "Actual", never);
+
+    assert_eq!(err.expected(), "Expected");
+    assert!(err.into_value().is_none());
+}
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(infallible)]
+enum Wrapper<T> {
+    Value(T),
+}
+
+#[test]
+fn test_infallible() {
+    let mut wrapper = Wrapper::Value(1);
+
+    assert_eq!(*wrapper.as_value(), 1);
+    *wrapper.as_value_mut() += 1;
+    assert_eq!(wrapper.into_value(), 2);
+}
+
+#[derive(Debug, EnumTryAsInner)]
+#[derive_err(Debug)]
+#[try_as_inner(infallible)]
+enum Point {
+    #[try_as_inner(rename = "coords")]
+    Xy { x: i32, y: i32 },
+}
+
+#[test]
+fn test_infallible_named() {
+    let point = Point::Xy { x: 1, y: 2 };
+
+    assert_eq!(point.as_coords(), (&1, &2));
+    // the fallible accessors are still generated
+    assert_eq!(point.try_into_coords().unwrap(), (1, 2));
+}
@@ -0,0 +1,72 @@
+//! Tests for the visibility of the generated methods and errors.
+
+#![warn(
+    clippy::default_trait_access,
+    clippy::dbg_macro,
+    clippy::print_stdout,
+    clippy::unimplemented,
+    clippy::use_self,
+    missing_copy_implementations,
+    missing_docs,
+    non_snake_case,
+    non_upper_case_globals,
+    rust_2018_idioms,
+    unreachable_pub
+)]
+
+pub mod name_collisions {
+    #![allow(dead_code, missing_copy_implementations, missing_docs)]
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+}
+#[allow(unused_imports)]
+use name_collisions::*;
+
+mod messages {
+    use enum_try_as_inner::EnumTryAsInner;
+
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(vis = "pub(super)")]
+    pub(crate) enum Message {
+        Text(String),
+        #[try_as_inner(vis = "pub(crate)")]
+        Code(u32),
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, EnumTryAsInner)]
+    #[derive_err(Debug)]
+    #[try_as_inner(err_vis = "pub(crate)")]
+    enum Internal {
+        Int(i64),
+        Empty,
+    }
+
+    pub(crate) fn internal_error() -> InternalError {
+        Internal::Empty.try_into_int().unwrap_err()
+    }
+}
+
+use messages::Message;
+
+#[test]
+fn test_method_vis() {
+    let text = Message::Text("text".into());
+
+    assert!(text.is_text());
+    assert_eq!(text.try_as_text().unwrap(), "text");
+    assert_eq!(Message::Code(1).try_into_code().unwrap(), 1);
+}
+
+#[test]
+fn test_err_vis() {
+    let err: messages::InternalError = messages::internal_error();
+
+    assert_eq!(err.expected(), "Int");
+    assert_eq!(err.actual(), "Empty");
+}